@@ -28,16 +28,16 @@ impl<'ctx> CodeGen<'ctx> {
         let function = self.module.add_function("sum", fn_type, None);
         let basic_block = self.context.append_basic_block(function, "entry");
 
-        self.builder.position_at_end(basic_block);
+        self.builder.position_at_end(basic_block).unwrap();
 
         let x = function.get_nth_param(0)?.into_int_value();
         let y = function.get_nth_param(1)?.into_int_value();
         let z = function.get_nth_param(2)?.into_int_value();
 
-        let sum = self.builder.build_int_add(x, y, "sum");
-        let sum = self.builder.build_int_add(sum, z, "sum");
+        let sum = self.builder.build_int_add(x, y, "sum").unwrap();
+        let sum = self.builder.build_int_add(sum, z, "sum").unwrap();
 
-        self.builder.build_return(Some(&sum));
+        self.builder.build_return(Some(&sum)).unwrap();
 
         unsafe { self.execution_engine.get_function("sum").ok() }
     }
@@ -875,11 +875,11 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
         let entry = self.fn_value().get_first_basic_block().unwrap();
 
         match entry.get_first_instruction() {
-            Some(first_instr) => builder.position_before(&first_instr),
-            None => builder.position_at_end(entry),
+            Some(first_instr) => builder.position_before(&first_instr).unwrap(),
+            None => builder.position_at_end(entry).unwrap(),
         }
 
-        builder.build_alloca(self.context.f64_type(), name)
+        builder.build_alloca(self.context.f64_type(), name).unwrap()
     }
 
     /// Compiles the specified `Expr` into an LLVM `FloatValue`.
@@ -890,7 +890,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
             Expr::Variable(ref name) => match self.variables.get(name.as_str()) {
                 Some(var) => Ok(self
                     .builder
-                    .build_load(*var, name.as_str())
+                    .build_load(*var, name.as_str())?
                     .into_float_value()),
                 None => Err("Could not find a matching variable."),
             },
@@ -911,7 +911,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
 
                     let alloca = self.create_entry_block_alloca(var_name);
 
-                    self.builder.build_store(alloca, initial_val);
+                    self.builder.build_store(alloca, initial_val)?;
 
                     if let Some(old_binding) = self.variables.remove(var_name) {
                         old_bindings.push(old_binding);
@@ -950,7 +950,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
                         .get(var_name.as_str())
                         .ok_or("Undefined variable.")?;
 
-                    self.builder.build_store(*var, var_val);
+                    self.builder.build_store(*var, var_val)?;
 
                     Ok(var_val)
                 } else {
@@ -958,38 +958,38 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
                     let rhs = self.compile_expr(right)?;
 
                     match op {
-                        '+' => Ok(self.builder.build_float_add(lhs, rhs, "tmpadd")),
-                        '-' => Ok(self.builder.build_float_sub(lhs, rhs, "tmpsub")),
-                        '*' => Ok(self.builder.build_float_mul(lhs, rhs, "tmpmul")),
-                        '/' => Ok(self.builder.build_float_div(lhs, rhs, "tmpdiv")),
-                        '<' => Ok({
+                        '+' => self.builder.build_float_add(lhs, rhs, "tmpadd"),
+                        '-' => self.builder.build_float_sub(lhs, rhs, "tmpsub"),
+                        '*' => self.builder.build_float_mul(lhs, rhs, "tmpmul"),
+                        '/' => self.builder.build_float_div(lhs, rhs, "tmpdiv"),
+                        '<' => {
                             let cmp = self.builder.build_float_compare(
                                 FloatPredicate::ULT,
                                 lhs,
                                 rhs,
                                 "tmpcmp",
-                            );
+                            )?;
 
                             self.builder.build_unsigned_int_to_float(
                                 cmp,
                                 self.context.f64_type(),
                                 "tmpbool",
                             )
-                        }),
-                        '>' => Ok({
+                        }
+                        '>' => {
                             let cmp = self.builder.build_float_compare(
                                 FloatPredicate::ULT,
                                 rhs,
                                 lhs,
                                 "tmpcmp",
-                            );
+                            )?;
 
                             self.builder.build_unsigned_int_to_float(
                                 cmp,
                                 self.context.f64_type(),
                                 "tmpbool",
                             )
-                        }),
+                        }
 
                         custom => {
                             let mut name = String::from("binary");
@@ -1000,7 +1000,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
                                 Some(fun) => {
                                     match self
                                         .builder
-                                        .build_call(fun, &[lhs.into(), rhs.into()], "tmpbin")
+                                        .build_call(fun, &[lhs.into(), rhs.into()], "tmpbin")?
                                         .try_as_basic_value()
                                         .left()
                                     {
@@ -1035,7 +1035,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
 
                     match self
                         .builder
-                        .build_call(fun, argsv.as_slice(), "tmp")
+                        .build_call(fun, argsv.as_slice(), "tmp")?
                         .try_as_basic_value()
                         .left()
                     {
@@ -1061,7 +1061,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
                     cond,
                     zero_const,
                     "ifcond",
-                );
+                )?;
 
                 // build branch
                 let then_bb = self.context.append_basic_block(parent, "then");
@@ -1069,26 +1069,26 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
                 let cont_bb = self.context.append_basic_block(parent, "ifcont");
 
                 self.builder
-                    .build_conditional_branch(cond, then_bb, else_bb);
+                    .build_conditional_branch(cond, then_bb, else_bb)?;
 
                 // build then block
-                self.builder.position_at_end(then_bb);
+                self.builder.position_at_end(then_bb)?;
                 let then_val = self.compile_expr(consequence)?;
-                self.builder.build_unconditional_branch(cont_bb);
+                self.builder.build_unconditional_branch(cont_bb)?;
 
                 let then_bb = self.builder.get_insert_block().unwrap();
 
                 // build else block
-                self.builder.position_at_end(else_bb);
+                self.builder.position_at_end(else_bb)?;
                 let else_val = self.compile_expr(alternative)?;
-                self.builder.build_unconditional_branch(cont_bb);
+                self.builder.build_unconditional_branch(cont_bb)?;
 
                 let else_bb = self.builder.get_insert_block().unwrap();
 
                 // emit merge block
-                self.builder.position_at_end(cont_bb);
+                self.builder.position_at_end(cont_bb)?;
 
-                let phi = self.builder.build_phi(self.context.f64_type(), "iftmp");
+                let phi = self.builder.build_phi(self.context.f64_type(), "iftmp")?;
 
                 phi.add_incoming(&[(&then_val, then_bb), (&else_val, else_bb)]);
 
@@ -1107,13 +1107,13 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
                 let start_alloca = self.create_entry_block_alloca(var_name);
                 let start = self.compile_expr(start)?;
 
-                self.builder.build_store(start_alloca, start);
+                self.builder.build_store(start_alloca, start)?;
 
                 // go from current block to loop block
                 let loop_bb = self.context.append_basic_block(parent, "loop");
 
-                self.builder.build_unconditional_branch(loop_bb);
-                self.builder.position_at_end(loop_bb);
+                self.builder.build_unconditional_branch(loop_bb)?;
+                self.builder.position_at_end(loop_bb)?;
 
                 let old_val = self.variables.remove(var_name.as_str());
 
@@ -1131,24 +1131,24 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
                 // compile end condition
                 let end_cond = self.compile_expr(end)?;
 
-                let curr_var = self.builder.build_load(start_alloca, var_name);
+                let curr_var = self.builder.build_load(start_alloca, var_name)?;
                 let next_var =
                     self.builder
-                        .build_float_add(curr_var.into_float_value(), step, "nextvar");
+                        .build_float_add(curr_var.into_float_value(), step, "nextvar")?;
 
-                self.builder.build_store(start_alloca, next_var);
+                self.builder.build_store(start_alloca, next_var)?;
 
                 let end_cond = self.builder.build_float_compare(
                     FloatPredicate::ONE,
                     end_cond,
                     self.context.f64_type().const_float(0.0),
                     "loopcond",
-                );
+                )?;
                 let after_bb = self.context.append_basic_block(parent, "afterloop");
 
                 self.builder
-                    .build_conditional_branch(end_cond, loop_bb, after_bb);
-                self.builder.position_at_end(after_bb);
+                    .build_conditional_branch(end_cond, loop_bb, after_bb)?;
+                self.builder.position_at_end(after_bb)?;
 
                 self.variables.remove(var_name);
 
@@ -1194,7 +1194,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
 
         let entry = self.context.append_basic_block(function, "entry");
 
-        self.builder.position_at_end(entry);
+        self.builder.position_at_end(entry)?;
 
         // update fn field
         self.fn_value_opt = Some(function);
@@ -1206,7 +1206,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
             let arg_name = proto.args[i].as_str();
             let alloca = self.create_entry_block_alloca(arg_name);
 
-            self.builder.build_store(alloca, arg);
+            self.builder.build_store(alloca, arg)?;
 
             self.variables.insert(proto.args[i].clone(), alloca);
         }
@@ -1214,7 +1214,7 @@ impl<'a, 'ctx> Compiler<'a, 'ctx> {
         // compile body
         let body = self.compile_expr(self.function.body.as_ref().unwrap())?;
 
-        self.builder.build_return(Some(&body));
+        self.builder.build_return(Some(&body))?;
 
         // return the whole thing after verification and optimization
         if function.verify(true) {
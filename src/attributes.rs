@@ -177,6 +177,89 @@ impl Attribute {
     }
 }
 
+/// A well known enum `Attribute` kind, whose kind id is looked up by name
+/// in the LLVM version being linked against.
+#[llvm_versions(3.9..=latest)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum EnumAttributeKind {
+    AlwaysInline,
+    ByVal,
+    Cold,
+    InlineHint,
+    InReg,
+    MinSize,
+    Naked,
+    Nest,
+    NoAlias,
+    NoCapture,
+    NoInline,
+    NonNull,
+    NoRecurse,
+    NoReturn,
+    NoUnwind,
+    OptimizeForSize,
+    OptimizeNone,
+    ReadNone,
+    ReadOnly,
+    Returned,
+    SExt,
+    StructRet,
+    ZExt,
+}
+
+#[llvm_versions(3.9..=latest)]
+impl EnumAttributeKind {
+    /// Gets the name LLVM uses for this kind of `Attribute` in textual IR.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::EnumAttributeKind;
+    ///
+    /// assert_eq!(EnumAttributeKind::NoAlias.get_name(), "noalias");
+    /// ```
+    pub fn get_name(self) -> &'static str {
+        match self {
+            EnumAttributeKind::AlwaysInline => "alwaysinline",
+            EnumAttributeKind::ByVal => "byval",
+            EnumAttributeKind::Cold => "cold",
+            EnumAttributeKind::InlineHint => "inlinehint",
+            EnumAttributeKind::InReg => "inreg",
+            EnumAttributeKind::MinSize => "minsize",
+            EnumAttributeKind::Naked => "naked",
+            EnumAttributeKind::Nest => "nest",
+            EnumAttributeKind::NoAlias => "noalias",
+            EnumAttributeKind::NoCapture => "nocapture",
+            EnumAttributeKind::NoInline => "noinline",
+            EnumAttributeKind::NonNull => "nonnull",
+            EnumAttributeKind::NoRecurse => "norecurse",
+            EnumAttributeKind::NoReturn => "noreturn",
+            EnumAttributeKind::NoUnwind => "nounwind",
+            EnumAttributeKind::OptimizeForSize => "optsize",
+            EnumAttributeKind::OptimizeNone => "optnone",
+            EnumAttributeKind::ReadNone => "readnone",
+            EnumAttributeKind::ReadOnly => "readonly",
+            EnumAttributeKind::Returned => "returned",
+            EnumAttributeKind::SExt => "signext",
+            EnumAttributeKind::StructRet => "sret",
+            EnumAttributeKind::ZExt => "zeroext",
+        }
+    }
+
+    /// Gets the kind id of this kind of `Attribute` in the LLVM version being linked against.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::{Attribute, EnumAttributeKind};
+    ///
+    /// assert_eq!(EnumAttributeKind::NoAlias.get_kind_id(), Attribute::get_named_enum_kind_id("noalias"));
+    /// ```
+    pub fn get_kind_id(self) -> u32 {
+        Attribute::get_named_enum_kind_id(self.get_name())
+    }
+}
+
 /// An `AttributeLoc` determines where on a function an attribute is assigned to.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum AttributeLoc {
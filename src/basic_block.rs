@@ -1,5 +1,7 @@
 //! A `BasicBlock` is a container of instructions.
 
+#[llvm_versions(10.0..=latest)]
+use llvm_sys::core::LLVMAppendExistingBasicBlock;
 #[llvm_versions(3.9..=latest)]
 use llvm_sys::core::LLVMGetBasicBlockName;
 use llvm_sys::core::{
@@ -12,6 +14,8 @@ use llvm_sys::core::{
 use llvm_sys::prelude::{LLVMBasicBlockRef, LLVMValueRef};
 
 use crate::context::ContextRef;
+#[llvm_versions(10.0..=latest)]
+use crate::values::AsValueRef;
 use crate::values::{BasicValueUse, FunctionValue, InstructionValue};
 
 use std::ffi::CStr;
@@ -232,8 +236,8 @@ impl<'ctx> BasicBlock<'ctx> {
     /// let function = module.add_function("do_nothing", fn_type, None);
     /// let basic_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(basic_block);
-    /// builder.build_return(None);
+    /// builder.position_at_end(basic_block).unwrap();
+    /// builder.build_return(None).unwrap();
     ///
     /// assert_eq!(basic_block.get_first_instruction().unwrap().get_opcode(), InstructionOpcode::Return);
     /// ```
@@ -264,8 +268,8 @@ impl<'ctx> BasicBlock<'ctx> {
     /// let function = module.add_function("do_nothing", fn_type, None);
     /// let basic_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(basic_block);
-    /// builder.build_return(None);
+    /// builder.position_at_end(basic_block).unwrap();
+    /// builder.build_return(None).unwrap();
     ///
     /// assert_eq!(basic_block.get_last_instruction().unwrap().get_opcode(), InstructionOpcode::Return);
     /// ```
@@ -281,6 +285,9 @@ impl<'ctx> BasicBlock<'ctx> {
 
     /// Obtains the terminating `InstructionValue` in this `BasicBlock`, if any. A `BasicBlock` must have a terminating instruction to be valid.
     ///
+    /// Unlike `get_last_instruction`, this returns `None` when the last instruction is not a terminator,
+    /// which makes it suitable for detecting blocks that were left unterminated.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::context::Context;
@@ -296,15 +303,13 @@ impl<'ctx> BasicBlock<'ctx> {
     /// let function = module.add_function("do_nothing", fn_type, None);
     /// let basic_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(basic_block);
-    /// builder.build_return(None);
+    /// builder.position_at_end(basic_block).unwrap();
+    /// builder.build_return(None).unwrap();
     ///
     /// assert_eq!(basic_block.get_terminator().unwrap().get_opcode(), InstructionOpcode::Return);
     /// ```
     // REVIEW: If we wanted the return type could be Option<Either<BasicValueEnum, InstructionValue>>
     // if getting a value over an instruction is preferable
-    // TODOC: Every BB must have a terminating instruction or else it is invalid
-    // REVIEW: Unclear how this differs from get_last_instruction
     pub fn get_terminator(self) -> Option<InstructionValue<'ctx>> {
         let value = unsafe { LLVMGetBasicBlockTerminator(self.basic_block) };
 
@@ -315,7 +320,8 @@ impl<'ctx> BasicBlock<'ctx> {
         Some(InstructionValue::new(value))
     }
 
-    /// Removes this `BasicBlock` from its parent `FunctionValue`.
+    /// Removes this `BasicBlock` from its parent `FunctionValue`. Unlike `delete`, the
+    /// `BasicBlock` is not freed and stays usable, so it may be appended to a function again.
     /// It returns `Err(())` when it has no parent to remove from.
     ///
     /// # Example
@@ -352,6 +358,37 @@ impl<'ctx> BasicBlock<'ctx> {
         Ok(())
     }
 
+    /// Appends this `BasicBlock`, which must have been removed from its parent beforehand,
+    /// to the end of `function`.
+    /// It returns `Err(())` when it still has a parent.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let function = module.add_function("do_nothing", fn_type, None);
+    /// let basic_block = context.append_basic_block(function, "entry");
+    ///
+    /// basic_block.remove_from_function().unwrap();
+    /// basic_block.append_to_function(function).unwrap();
+    ///
+    /// assert_eq!(basic_block.get_parent().unwrap(), function);
+    /// ```
+    #[llvm_versions(10.0..=latest)]
+    pub fn append_to_function(self, function: FunctionValue<'ctx>) -> Result<(), ()> {
+        if self.get_parent().is_some() {
+            return Err(());
+        }
+
+        unsafe { LLVMAppendExistingBasicBlock(function.as_value_ref(), self.basic_block) }
+
+        Ok(())
+    }
+
     /// Removes this `BasicBlock` completely from memory. This is unsafe because you could easily have other references to the same `BasicBlock`.
     /// It returns `Err(())` when it has no parent to delete from, as LLVM assumes it has a parent.
     ///
@@ -448,8 +485,8 @@ impl<'ctx> BasicBlock<'ctx> {
     /// let entry = context.append_basic_block(fn_val, "entry");
     /// let bb1 = context.append_basic_block(fn_val, "bb1");
     /// let bb2 = context.append_basic_block(fn_val, "bb2");
-    /// builder.position_at_end(entry);
-    /// let branch_inst = builder.build_unconditional_branch(bb1);
+    /// builder.position_at_end(entry).unwrap();
+    /// let branch_inst = builder.build_unconditional_branch(bb1).unwrap();
     ///
     /// bb1.replace_all_uses_with(&bb2);
     ///
@@ -485,8 +522,8 @@ impl<'ctx> BasicBlock<'ctx> {
     /// let entry = context.append_basic_block(fn_val, "entry");
     /// let bb1 = context.append_basic_block(fn_val, "bb1");
     /// let bb2 = context.append_basic_block(fn_val, "bb2");
-    /// builder.position_at_end(entry);
-    /// let branch_inst = builder.build_unconditional_branch(bb1);
+    /// builder.position_at_end(entry).unwrap();
+    /// let branch_inst = builder.build_unconditional_branch(bb1).unwrap();
     ///
     /// assert!(bb2.get_first_use().is_none());
     /// assert!(bb1.get_first_use().is_some());
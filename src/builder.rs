@@ -7,53 +7,94 @@ use llvm_sys::core::{
     LLVMAddCase, LLVMAddClause, LLVMAddDestination, LLVMBuildAShr, LLVMBuildAdd,
     LLVMBuildAddrSpaceCast, LLVMBuildAggregateRet, LLVMBuildAlloca, LLVMBuildAnd,
     LLVMBuildArrayAlloca, LLVMBuildArrayMalloc, LLVMBuildAtomicRMW, LLVMBuildBitCast, LLVMBuildBr,
-    LLVMBuildCall, LLVMBuildCast, LLVMBuildCatchPad, LLVMBuildCatchRet, LLVMBuildCatchSwitch,
-    LLVMBuildCondBr, LLVMBuildExactSDiv, LLVMBuildExtractElement, LLVMBuildExtractValue,
-    LLVMBuildFAdd, LLVMBuildFCmp, LLVMBuildFDiv, LLVMBuildFMul, LLVMBuildFNeg, LLVMBuildFPCast,
-    LLVMBuildFPExt, LLVMBuildFPToSI, LLVMBuildFPToUI, LLVMBuildFPTrunc, LLVMBuildFRem,
-    LLVMBuildFSub, LLVMBuildFence, LLVMBuildFree, LLVMBuildGEP, LLVMBuildGlobalString,
-    LLVMBuildGlobalStringPtr, LLVMBuildICmp, LLVMBuildInBoundsGEP, LLVMBuildIndirectBr,
-    LLVMBuildInsertElement, LLVMBuildInsertValue, LLVMBuildIntCast, LLVMBuildIntToPtr,
-    LLVMBuildInvoke, LLVMBuildIsNotNull, LLVMBuildIsNull, LLVMBuildLShr, LLVMBuildLandingPad,
-    LLVMBuildLoad, LLVMBuildMalloc, LLVMBuildMul, LLVMBuildNSWAdd, LLVMBuildNSWMul,
-    LLVMBuildNSWNeg, LLVMBuildNSWSub, LLVMBuildNUWAdd, LLVMBuildNUWMul, LLVMBuildNUWNeg,
-    LLVMBuildNUWSub, LLVMBuildNeg, LLVMBuildNot, LLVMBuildOr, LLVMBuildPhi, LLVMBuildPointerCast,
-    LLVMBuildPtrDiff, LLVMBuildPtrToInt, LLVMBuildRet, LLVMBuildRetVoid, LLVMBuildSDiv,
-    LLVMBuildSExt, LLVMBuildSExtOrBitCast, LLVMBuildSIToFP, LLVMBuildSRem, LLVMBuildSelect,
-    LLVMBuildShl, LLVMBuildShuffleVector, LLVMBuildStore, LLVMBuildStructGEP, LLVMBuildSub,
-    LLVMBuildSwitch, LLVMBuildTrunc, LLVMBuildTruncOrBitCast, LLVMBuildUDiv, LLVMBuildUIToFP,
-    LLVMBuildURem, LLVMBuildUnreachable, LLVMBuildVAArg, LLVMBuildXor, LLVMBuildZExt,
-    LLVMBuildZExtOrBitCast, LLVMClearInsertionPosition, LLVMDisposeBuilder, LLVMGetElementType,
-    LLVMGetInsertBlock, LLVMGetReturnType, LLVMGetTypeKind, LLVMInsertIntoBuilder,
-    LLVMInsertIntoBuilderWithName, LLVMPositionBuilder, LLVMPositionBuilderAtEnd,
-    LLVMPositionBuilderBefore, LLVMTypeOf,
+    LLVMBuildCall, LLVMBuildCast, LLVMBuildCondBr, LLVMBuildExactSDiv, LLVMBuildExtractElement,
+    LLVMBuildExtractValue, LLVMBuildFAdd, LLVMBuildFCmp, LLVMBuildFDiv, LLVMBuildFMul,
+    LLVMBuildFNeg, LLVMBuildFPCast, LLVMBuildFPExt, LLVMBuildFPToSI, LLVMBuildFPToUI,
+    LLVMBuildFPTrunc, LLVMBuildFRem, LLVMBuildFSub, LLVMBuildFence, LLVMBuildFree, LLVMBuildGEP,
+    LLVMBuildGlobalString, LLVMBuildGlobalStringPtr, LLVMBuildICmp, LLVMBuildInBoundsGEP,
+    LLVMBuildIndirectBr, LLVMBuildInsertElement, LLVMBuildInsertValue, LLVMBuildIntCast,
+    LLVMBuildIntToPtr, LLVMBuildInvoke, LLVMBuildIsNotNull, LLVMBuildIsNull, LLVMBuildLShr,
+    LLVMBuildLandingPad, LLVMBuildLoad, LLVMBuildMalloc, LLVMBuildMul, LLVMBuildNSWAdd,
+    LLVMBuildNSWMul, LLVMBuildNSWNeg, LLVMBuildNSWSub, LLVMBuildNUWAdd, LLVMBuildNUWMul,
+    LLVMBuildNUWNeg, LLVMBuildNUWSub, LLVMBuildNeg, LLVMBuildNot, LLVMBuildOr, LLVMBuildPhi,
+    LLVMBuildPointerCast, LLVMBuildPtrDiff, LLVMBuildPtrToInt, LLVMBuildResume, LLVMBuildRet,
+    LLVMBuildRetVoid, LLVMBuildSDiv, LLVMBuildSExt, LLVMBuildSExtOrBitCast, LLVMBuildSIToFP,
+    LLVMBuildSRem, LLVMBuildSelect, LLVMBuildShl, LLVMBuildShuffleVector, LLVMBuildStore,
+    LLVMBuildStructGEP, LLVMBuildSub, LLVMBuildSwitch, LLVMBuildTrunc, LLVMBuildTruncOrBitCast,
+    LLVMBuildUDiv, LLVMBuildUIToFP, LLVMBuildURem, LLVMBuildUnreachable, LLVMBuildVAArg,
+    LLVMBuildXor, LLVMBuildZExt, LLVMBuildZExtOrBitCast, LLVMClearInsertionPosition,
+    LLVMCountStructElementTypes, LLVMDisposeBuilder, LLVMGetElementType, LLVMGetGlobalParent,
+    LLVMGetInsertBlock, LLVMGetInstructionParent, LLVMGetIntTypeWidth, LLVMGetPointerAddressSpace,
+    LLVMGetReturnType, LLVMGetStructElementTypes, LLVMGetTypeKind, LLVMGetVectorSize,
+    LLVMInsertIntoBuilder, LLVMInsertIntoBuilderWithName, LLVMPositionBuilder,
+    LLVMPositionBuilderAtEnd, LLVMPositionBuilderBefore, LLVMSetCleanup, LLVMTypeOf,
+};
+#[llvm_versions(6.0..=latest)]
+use llvm_sys::core::{
+    LLVMAddHandler, LLVMBuildCatchPad, LLVMBuildCatchRet, LLVMBuildCatchSwitch,
+    LLVMBuildCleanupPad, LLVMBuildCleanupRet, LLVMConstNull, LLVMGetModuleContext,
+    LLVMTokenTypeInContext,
 };
 #[llvm_versions(8.0..=latest)]
-use llvm_sys::core::{LLVMBuildMemCpy, LLVMBuildMemMove};
-use llvm_sys::prelude::{LLVMBuilderRef, LLVMValueRef};
+use llvm_sys::core::{
+    LLVMBuildCall2, LLVMBuildMemCpy, LLVMBuildMemMove, LLVMCountParamTypes, LLVMGetParamTypes,
+};
+use llvm_sys::prelude::{
+    LLVMBasicBlockRef, LLVMBuilderRef, LLVMModuleRef, LLVMTypeRef, LLVMValueRef,
+};
+#[llvm_versions(3.9..=latest)]
+use llvm_sys::target::LLVMGetModuleDataLayout;
+use llvm_sys::target::LLVMPointerSizeForAS;
 use llvm_sys::LLVMTypeKind;
 
 use crate::basic_block::BasicBlock;
 #[llvm_versions(7.0..=latest)]
 use crate::debug_info::DILocation;
+use crate::module::{get_intrinsic_declaration, Module};
 use crate::support::to_c_str;
+#[llvm_versions(3.6..=3.8)]
+use crate::targets::TargetData;
+#[cfg(feature = "target-syncvm")]
+use crate::types::BasicTypeEnum;
+#[llvm_versions(8.0..=latest)]
+use crate::types::FunctionType;
 use crate::types::{
     AsTypeRef, BasicType, FloatMathType, IntMathType, PointerMathType, PointerType,
 };
+#[llvm_versions(8.0..=latest)]
+use crate::values::BasicMetadataValueEnum;
+#[llvm_versions(9.0..=latest)]
+use crate::values::FloatValue;
 #[llvm_versions(3.9..=latest)]
 use crate::values::StructValue;
 use crate::values::{
-    AggregateValue, AggregateValueEnum, AnyValueEnum, AsValueRef, BasicValue, BasicValueEnum,
-    CallSiteValue, FloatMathValue, FunctionValue, GlobalValue, InstructionOpcode, InstructionValue,
-    IntMathValue, IntValue, PhiValue, PointerMathValue, PointerValue, VectorValue,
+    AggregateValue, AggregateValueEnum, AsValueRef, BasicValue, BasicValueEnum, CallSiteValue,
+    FloatMathValue, FunctionValue, GlobalValue, InstructionOpcode, InstructionValue, IntMathValue,
+    IntValue, PhiValue, PointerMathValue, PointerValue, VectorValue,
 };
 use crate::{AtomicOrdering, AtomicRMWBinOp, FloatPredicate, IntPredicate};
 
+use std::cell::Cell;
 use std::marker::PhantomData;
+use std::ptr;
+
+/// The terminator `Builder::ensure_terminated` falls back to building when a block has none.
+#[derive(Debug, Clone, Copy)]
+pub enum Terminator<'ctx> {
+    /// An `unreachable` instruction.
+    Unreachable,
+    /// A `ret` instruction, returning the value if there is one and `void` otherwise.
+    Return(Option<BasicValueEnum<'ctx>>),
+    /// An unconditional `br` instruction to the block.
+    Branch(BasicBlock<'ctx>),
+}
 
 #[derive(Debug)]
 pub struct Builder<'ctx> {
     builder: LLVMBuilderRef,
+    check_ptr_int_widths: Cell<bool>,
+    validate_terminators: Cell<bool>,
+    positioned_at_end: Cell<bool>,
     _marker: PhantomData<&'ctx ()>,
 }
 
@@ -63,10 +104,127 @@ impl<'ctx> Builder<'ctx> {
 
         Builder {
             builder,
+            check_ptr_int_widths: Cell::new(false),
+            validate_terminators: Cell::new(false),
+            positioned_at_end: Cell::new(false),
             _marker: PhantomData,
         }
     }
 
+    /// Makes `build_int_to_ptr` and `build_ptr_to_int` return `Err` when the width of the int doesn't
+    /// match the size of pointers in the address space, according to the data layout of the module the
+    /// builder is positioned in. This is off by default, as LLVM truncates or zero extends ints of other
+    /// widths, which is valid IR, but on targets with several address spaces such casts are often a mistake.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::TargetData;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let builder = context.create_builder();
+    /// let i32_ptr_type = context.i32_type().ptr_type(AddressSpace::Zero);
+    /// let fn_type = context.void_type().fn_type(&[i32_ptr_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    ///
+    /// module.set_data_layout(&TargetData::create("e-p:32:32").get_data_layout());
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// assert!(builder.build_ptr_to_int(ptr, context.i64_type(), "int").is_ok());
+    ///
+    /// builder.set_check_ptr_int_widths(true);
+    ///
+    /// assert!(builder.build_ptr_to_int(ptr, context.i64_type(), "int").is_err());
+    /// assert!(builder.build_ptr_to_int(ptr, context.i32_type(), "int").is_ok());
+    /// ```
+    pub fn set_check_ptr_int_widths(&self, check: bool) {
+        self.check_ptr_int_widths.set(check);
+    }
+
+    /// Makes the builder check that blocks are terminated exactly once as they are built, so that such
+    /// mistakes surface where they are made rather than when the module is verified. While enabled,
+    /// building a terminator into a block which already has one returns `Err`, as does building any
+    /// other instruction at the end of an already terminated block, positioning at the end of such a
+    /// block, or positioning anywhere outside of the current block while it is still unterminated.
+    /// Instructions may still be built before the terminator, such as after `position_before`.
+    /// This is off by default.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let exit = context.append_basic_block(fn_value, "exit");
+    ///
+    /// builder.set_validate_terminators(true);
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// assert!(builder.position_at_end(exit).is_err());
+    ///
+    /// let branch = builder.build_unconditional_branch(exit).unwrap();
+    ///
+    /// assert!(builder.build_return(None).is_err());
+    /// assert!(builder.build_alloca(context.i32_type(), "slot").is_err());
+    ///
+    /// builder.position_before(&branch).unwrap();
+    ///
+    /// assert!(builder.build_alloca(context.i32_type(), "slot").is_ok());
+    /// assert!(builder.position_at_end(exit).is_ok());
+    /// ```
+    pub fn set_validate_terminators(&self, validate: bool) {
+        self.validate_terminators.set(validate);
+    }
+
+    fn check_can_build(&self) -> Result<(), &'static str> {
+        if !self.validate_terminators.get() || !self.positioned_at_end.get() {
+            return Ok(());
+        }
+
+        match self.get_insert_block() {
+            Some(block) if block.get_terminator().is_some() => {
+                Err("The block the builder is positioned at the end of is already terminated.")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_can_terminate(&self) -> Result<(), &'static str> {
+        if !self.validate_terminators.get() {
+            return Ok(());
+        }
+
+        match self.get_insert_block() {
+            Some(block) if block.get_terminator().is_some() => {
+                Err("The block the builder is positioned in is already terminated.")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn check_can_leave(&self, destination: LLVMBasicBlockRef) -> Result<(), &'static str> {
+        if !self.validate_terminators.get() {
+            return Ok(());
+        }
+
+        match self.get_insert_block() {
+            Some(block) if block.basic_block != destination && block.get_terminator().is_none() => {
+                Err("The current block must be terminated before positioning the builder elsewhere.")
+            }
+            _ => Ok(()),
+        }
+    }
+
     // REVIEW: Would probably make this API a bit simpler by taking Into<Option<&BasicValue>>
     // So that you could just do build_return(&value) or build_return(None). Is that frowned upon?
     /// Builds a function return instruction. It should be provided with `None` if the return type
@@ -88,10 +246,15 @@ impl<'ctx> Builder<'ctx> {
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_arg = fn_value.get_first_param().unwrap();
     ///
-    /// builder.position_at_end(entry);
-    /// builder.build_return(Some(&i32_arg));
+    /// builder.position_at_end(entry).unwrap();
+    /// builder.build_return(Some(&i32_arg)).unwrap();
     /// ```
-    pub fn build_return(&self, value: Option<&dyn BasicValue<'ctx>>) -> InstructionValue<'ctx> {
+    pub fn build_return(
+        &self,
+        value: Option<&dyn BasicValue<'ctx>>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
         let value = unsafe {
             value.map_or_else(
                 || LLVMBuildRetVoid(self.builder),
@@ -99,7 +262,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        InstructionValue::new(value)
+        Ok(InstructionValue::new(value))
     }
 
     /// Builds a function return instruction for a return type which is an aggregate type (ie structs and arrays).
@@ -122,23 +285,24 @@ impl<'ctx> Builder<'ctx> {
     /// let fn_value = module.add_function("ret", fn_type, None);
     /// let entry = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry);
-    /// builder.build_aggregate_return(&[i32_three.into(), i32_seven.into()]);
+    /// builder.position_at_end(entry).unwrap();
+    /// builder.build_aggregate_return(&[i32_three.into(), i32_seven.into()]).unwrap();
     /// ```
     pub fn build_aggregate_return(
         &self,
         values: &[BasicValueEnum<'ctx>],
-    ) -> InstructionValue<'ctx> {
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
         let mut args: Vec<LLVMValueRef> = values.iter().map(|val| val.as_value_ref()).collect();
         let value =
             unsafe { LLVMBuildAggregateRet(self.builder, args.as_mut_ptr(), args.len() as u32) };
 
-        InstructionValue::new(value)
+        Ok(InstructionValue::new(value))
     }
 
     /// Builds a function call instruction. It can take either a `FunctionValue` or a `PointerValue`
-    /// which is a function pointer. It will panic if the `PointerValue` is not a function pointer.
-    /// This may be turned into a Result in the future, however.
+    /// which is a function pointer. Returns `Err` if the `PointerValue` is not a function pointer.
     ///
     /// # Example
     ///
@@ -155,24 +319,26 @@ impl<'ctx> Builder<'ctx> {
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_arg = fn_value.get_first_param().unwrap();
     ///
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     ///
-    /// let ret_val = builder.build_call(fn_value, &[i32_arg], "call")
+    /// let ret_val = builder.build_call(fn_value, &[i32_arg], "call").unwrap()
     ///     .try_as_basic_value()
     ///     .left()
     ///     .unwrap();
     ///
-    /// builder.build_return(Some(&ret_val));
+    /// builder.build_return(Some(&ret_val)).unwrap();
     /// ```
     pub fn build_call<F>(
         &self,
         function: F,
         args: &[BasicValueEnum<'ctx>],
         name: &str,
-    ) -> CallSiteValue<'ctx>
+    ) -> Result<CallSiteValue<'ctx>, &'static str>
     where
         F: Into<FunctionOrPointerValue<'ctx>>,
     {
+        self.check_can_build()?;
+
         let fn_val_ref = match function.into() {
             Left(val) => val.as_value_ref(),
             Right(val) => {
@@ -184,11 +350,9 @@ impl<'ctx> Builder<'ctx> {
                     _ => false,
                 };
 
-                // REVIEW: We should probably turn this into a Result?
-                assert!(
-                    is_a_fn_ptr,
-                    "build_call called with a pointer which is not a function pointer"
-                );
+                if !is_a_fn_ptr {
+                    return Err("The pointer passed to build_call is not a function pointer.");
+                }
 
                 value_ref
             }
@@ -216,14 +380,109 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        CallSiteValue::new(value)
+        Ok(CallSiteValue::new(value))
+    }
+
+    /// Builds a call through the function pointer `fn_ptr`, which is called as a function of
+    /// type `fn_type` rather than one inferred from the pointer. Returns `Err` if `fn_ptr` doesn't
+    /// point to a function of that type, or if `args` don't match its parameters in number and type.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("indirect");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let callee_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let callee_ptr_type = callee_type.ptr_type(AddressSpace::Zero);
+    /// let fn_type = i32_type.fn_type(&[callee_ptr_type.into(), i32_type.into()], false);
+    /// let fn_value = module.add_function("apply", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let callee = fn_value.get_first_param().unwrap().into_pointer_value();
+    /// let arg = fn_value.get_last_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// let ret_val = builder.build_indirect_call(callee_type, callee, &[arg.into()], "call")
+    ///     .unwrap()
+    ///     .try_as_basic_value()
+    ///     .left()
+    ///     .unwrap();
+    ///
+    /// builder.build_return(Some(&ret_val)).unwrap();
+    /// ```
+    #[llvm_versions(8.0..=latest)]
+    pub fn build_indirect_call(
+        &self,
+        fn_type: FunctionType<'ctx>,
+        fn_ptr: PointerValue<'ctx>,
+        args: &[BasicMetadataValueEnum<'ctx>],
+        name: &str,
+    ) -> Result<CallSiteValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        let fn_type_ref = fn_type.as_type_ref();
+
+        if unsafe { LLVMGetElementType(fn_ptr.get_type().as_type_ref()) } != fn_type_ref {
+            return Err("The function pointer passed to build_indirect_call must point to a function of the given type.");
+        }
+
+        let param_count = unsafe { LLVMCountParamTypes(fn_type_ref) } as usize;
+        let mut param_types = Vec::with_capacity(param_count);
+
+        unsafe {
+            LLVMGetParamTypes(fn_type_ref, param_types.as_mut_ptr());
+            param_types.set_len(param_count);
+        }
+
+        let is_arg_count_ok = if fn_type.is_var_arg() {
+            args.len() >= param_count
+        } else {
+            args.len() == param_count
+        };
+
+        if !is_arg_count_ok {
+            return Err("The number of arguments passed to build_indirect_call does not match the function type.");
+        }
+
+        let mut args: Vec<LLVMValueRef> = args.iter().map(|val| val.as_value_ref()).collect();
+        let are_arg_types_ok = args
+            .iter()
+            .zip(param_types.iter())
+            .all(|(&arg, &param_type)| unsafe { LLVMTypeOf(arg) } == param_type);
+
+        if !are_arg_types_ok {
+            return Err("The types of the arguments passed to build_indirect_call do not match the function type.");
+        }
+
+        // LLVM gets upset when void return calls are named because they don't return anything
+        let name = match fn_type.get_return_type() {
+            None => "",
+            Some(_) => name,
+        };
+        let c_string = to_c_str(name);
+        let value = unsafe {
+            LLVMBuildCall2(
+                self.builder,
+                fn_type_ref,
+                fn_ptr.as_value_ref(),
+                args.as_mut_ptr(),
+                args.len() as u32,
+                c_string.as_ptr(),
+            )
+        };
+
+        Ok(CallSiteValue::new(value))
     }
 
     /// Builds a function invoke instruction. It can take either a `FunctionValue` or a `PointerValue`
-    /// which is a function pointer. It will panic if the `PointerValue` is not a function pointer.
+    /// which is a function pointer. Returns `Err` if the `PointerValue` is not a function pointer.
     /// The control flow is normally transferred to `then_block`, or to the `catch_block`, if an
     /// exception has been thrown.
-    /// This may be turned into a Result in the future, however.
     ///
     /// # Example
     ///
@@ -242,14 +501,14 @@ impl<'ctx> Builder<'ctx> {
     /// let catch = context.append_basic_block(fn_value, "catch");
     /// let i32_arg = fn_value.get_first_param().unwrap();
     ///
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     ///
-    /// let ret_val = builder.build_invoke(fn_value, &[i32_arg], join, catch, "call")
+    /// let ret_val = builder.build_invoke(fn_value, &[i32_arg], join, catch, "call").unwrap()
     ///     .try_as_basic_value()
     ///     .left()
     ///     .unwrap();
     ///
-    /// builder.build_return(Some(&ret_val));
+    /// builder.build_return(Some(&ret_val)).unwrap();
     /// ```
     pub fn build_invoke<F>(
         &self,
@@ -258,10 +517,12 @@ impl<'ctx> Builder<'ctx> {
         then_block: BasicBlock<'ctx>,
         catch_block: BasicBlock<'ctx>,
         name: &str,
-    ) -> CallSiteValue<'ctx>
+    ) -> Result<CallSiteValue<'ctx>, &'static str>
     where
         F: Into<FunctionOrPointerValue<'ctx>>,
     {
+        self.check_can_terminate()?;
+
         let fn_val_ref = match function.into() {
             Left(val) => val.as_value_ref(),
             Right(val) => {
@@ -273,11 +534,9 @@ impl<'ctx> Builder<'ctx> {
                     _ => false,
                 };
 
-                // REVIEW: We should probably turn this into a Result?
-                assert!(
-                    is_a_fn_ptr,
-                    "build_invoke called with a pointer which is not a function pointer"
-                );
+                if !is_a_fn_ptr {
+                    return Err("The pointer passed to build_invoke is not a function pointer.");
+                }
 
                 value_ref
             }
@@ -308,21 +567,213 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        CallSiteValue::new(value)
+        Ok(CallSiteValue::new(value))
     }
 
-    /// Builds an exception catching landing pad.
+    /// Builds a call to one of the SyncVM specific intrinsics by its name without the `llvm.syncvm.`
+    /// prefix, such as `"sload"` for `llvm.syncvm.sload`. The intrinsic is declared with the signature
+    /// the backend expects, which `args` are checked against, as calls with a wrong signature would
+    /// only crash later on in the backend.
+    /// Returns `Err` if the builder isn't positioned inside of a function, if the intrinsic doesn't
+    /// exist, or if `args` don't match its signature.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("syncvm");
+    /// let builder = context.create_builder();
+    /// let i256_type = context.custom_width_int_type(256);
+    /// let fn_type = i256_type.fn_type(&[i256_type.into()], false);
+    /// let fn_value = module.add_function("load_slot", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let key = fn_value.get_first_param().unwrap();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// let value = builder
+    ///     .build_syncvm_intrinsic_call("sload", &[key], "value")
+    ///     .unwrap()
+    ///     .try_as_basic_value()
+    ///     .left()
+    ///     .unwrap();
+    ///
+    /// builder.build_return(Some(&value)).unwrap();
+    /// ```
+    #[cfg(feature = "target-syncvm")]
+    pub fn build_syncvm_intrinsic_call(
+        &self,
+        intrinsic: &str,
+        args: &[BasicValueEnum<'ctx>],
+        name: &str,
+    ) -> Result<CallSiteValue<'ctx>, &'static str> {
+        let intrinsic = self.get_syncvm_intrinsic(intrinsic)?;
+
+        if !Builder::are_intrinsic_args_ok(intrinsic, args) {
+            return Err("The arguments don't match the signature of the intrinsic.");
+        }
+
+        self.build_call(intrinsic, args, name).unwrap()
+    }
+
+    /// Builds a SyncVM near call of `callee` through the `llvm.syncvm.nearcall` intrinsic, passing
+    /// `abi_data` along with the `args` of `callee`.
+    ///
+    /// Without an `exception_handler`, a failing near call propagates to the caller. Otherwise the
+    /// current block is terminated by invoking the near call, unwinding to `exception_handler` on failure,
+    /// and the builder is positioned at the end of a new block following the current one, where execution
+    /// continues on success.
+    ///
+    /// The intrinsic takes every callee as a pointer to the same function type, so `callee` is cast to it
+    /// once `args` have been checked against the signature of `callee` itself.
+    ///
+    /// Returns `Err` if the builder isn't positioned inside of a function, if `args` don't match the
+    /// parameters of `callee`, or if the intrinsic doesn't exist or doesn't accept these arguments.
+    #[cfg(feature = "target-syncvm")]
+    pub fn build_syncvm_near_call(
+        &self,
+        callee: FunctionValue<'ctx>,
+        abi_data: IntValue<'ctx>,
+        args: &[BasicValueEnum<'ctx>],
+        exception_handler: Option<BasicBlock<'ctx>>,
+        name: &str,
+    ) -> Result<CallSiteValue<'ctx>, &'static str> {
+        use llvm_sys::core::{
+            LLVMAppendBasicBlockInContext, LLVMGetModuleContext, LLVMInsertBasicBlockInContext,
+        };
+
+        if !Builder::are_intrinsic_args_ok(callee, args) {
+            return Err("The arguments don't match the signature of the near call's callee.");
+        }
+
+        let intrinsic = self.get_syncvm_intrinsic("nearcall")?;
+        let mut callee_ptr = callee.as_global_value().as_pointer_value();
+
+        if let Some(BasicTypeEnum::PointerType(ptr_type)) =
+            intrinsic.get_type().get_param_types().first()
+        {
+            if callee_ptr.get_type() != *ptr_type {
+                callee_ptr = self.build_pointer_cast(callee_ptr, *ptr_type, "near_call_callee")?;
+            }
+        }
+
+        let mut near_call_args = vec![callee_ptr.into(), abi_data.into()];
+
+        near_call_args.extend_from_slice(args);
+
+        if !Builder::are_intrinsic_args_ok(intrinsic, &near_call_args) {
+            return Err("The arguments don't match the signature of the intrinsic.");
+        }
+
+        let exception_handler = match exception_handler {
+            Some(exception_handler) => exception_handler,
+            None => return self.build_call(intrinsic, &near_call_args, name).unwrap(),
+        };
+
+        // Checked before the join block is added, so it isn't left behind on failure
+        self.check_can_terminate()?;
+
+        // get_syncvm_intrinsic has already made sure the builder is positioned inside of a function
+        let current_block = self.get_insert_block().unwrap();
+        let function = current_block.get_parent().unwrap();
+        let join_name = to_c_str("near_call_join");
+        let join_block = unsafe {
+            let context = LLVMGetModuleContext(LLVMGetGlobalParent(function.as_value_ref()));
+            let join_block = match current_block.get_next_basic_block() {
+                Some(next_block) => LLVMInsertBasicBlockInContext(
+                    context,
+                    next_block.basic_block,
+                    join_name.as_ptr(),
+                ),
+                None => LLVMAppendBasicBlockInContext(
+                    context,
+                    function.as_value_ref(),
+                    join_name.as_ptr(),
+                ),
+            };
+
+            BasicBlock::new(join_block).expect("Appending a basic block should never fail")
+        };
+
+        let call_site = self.build_invoke(
+            intrinsic,
+            &near_call_args,
+            join_block,
+            exception_handler,
+            name,
+        )?;
+
+        // The current block has just been terminated by the invoke
+        self.position_at_end(join_block)?;
+
+        Ok(call_site)
+    }
+
+    // TODO: build_call_with_operand_bundles for `"funclet"` and `"deopt"` bundles. LLVMCreateOperandBundle
+    // and LLVMBuildCallWithOperandBundles were only added to the C API in LLVM 18, so bundles can't be
+    // attached to calls for any of the supported versions yet. Once they are, catchpads and cleanuppads
+    // should be exposed as token values (ie an InstructionValue::as_token_value) so they can be passed
+    // as a bundle's operand. Until then pads are only referenced as InstructionValues, which is enough
+    // for nesting them as parent pads and for catchret/cleanupret.
+
+    // TODO: build_call_br for callbr (asm goto). The LLVM C API doesn't expose LLVMBuildCallBr
+    // for any of the supported versions, so this can't be built without a C++ shim for now.
+
+    /// Builds an exception catching landing pad, which is the destination of the unwind edge of
+    /// an invoke. It can take either a `FunctionValue` or a `PointerValue` which is a function pointer
+    /// as its personality function. Returns `Err` if the personality is not a function pointer or if
+    /// the landing pad has no clauses while not being a cleanup, as LLVM would reject it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("eh");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Zero);
+    /// let exception_type = context.struct_type(&[i8_ptr_type.into(), context.i32_type().into()], false);
+    /// let personality = module.add_function("__gxx_personality_v0", context.i32_type().fn_type(&[], true), None);
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let may_throw = module.add_function("may_throw", fn_type, None);
+    /// let fn_value = module.add_function("catches", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let then = context.append_basic_block(fn_value, "then");
+    /// let catch = context.append_basic_block(fn_value, "catch");
+    ///
+    /// fn_value.set_personality_function(personality);
+    /// builder.position_at_end(entry).unwrap();
+    /// builder.build_invoke(may_throw, &[], then, catch, "call").unwrap();
+    /// builder.position_at_end(then).unwrap();
+    /// builder.build_return(None).unwrap();
+    /// builder.position_at_end(catch).unwrap();
+    ///
+    /// let catch_all = i8_ptr_type.const_null();
+    /// let exception = builder
+    ///     .build_landing_pad(exception_type, personality, &[catch_all.into()], false, "exception")
+    ///     .unwrap();
+    ///
+    /// builder.build_resume(exception).unwrap();
+    /// ```
     pub fn build_landing_pad<T: BasicType<'ctx>, F>(
         &self,
-        ty: T,
-        pers_fn: F,
-        clauses: Vec<BasicValueEnum<'ctx>>,
+        exception_ty: T,
+        personality: F,
+        clauses: &[BasicValueEnum<'ctx>],
+        is_cleanup: bool,
         name: &str,
-    ) -> AnyValueEnum<'ctx>
+    ) -> Result<BasicValueEnum<'ctx>, &'static str>
     where
         F: Into<FunctionOrPointerValue<'ctx>>,
     {
-        let fn_val_ref = match pers_fn.into() {
+        self.check_can_build()?;
+
+        let fn_val_ref = match personality.into() {
             Left(val) => val.as_value_ref(),
             Right(val) => {
                 // If using a pointer value, we must validate it's a valid function ptr
@@ -333,45 +784,270 @@ impl<'ctx> Builder<'ctx> {
                     _ => false,
                 };
 
-                // REVIEW: We should probably turn this into a Result?
-                assert!(
-                    is_a_fn_ptr,
-                    "build_invoke called with a pointer which is not a function pointer"
-                );
+                if !is_a_fn_ptr {
+                    return Err(
+                        "The personality passed to build_landing_pad is not a function pointer.",
+                    );
+                }
 
                 value_ref
             }
         };
 
+        if clauses.is_empty() && !is_cleanup {
+            return Err("A landing pad must either have at least one clause or be a cleanup.");
+        }
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
             LLVMBuildLandingPad(
                 self.builder,
-                ty.as_type_ref(),
+                exception_ty.as_type_ref(),
                 fn_val_ref,
                 clauses.len() as u32,
                 c_string.as_ptr(),
             )
         };
 
-        for clause in clauses.into_iter() {
+        for clause in clauses {
             unsafe {
                 LLVMAddClause(value, clause.as_value_ref());
             }
         }
 
-        AnyValueEnum::new(value)
+        unsafe {
+            LLVMSetCleanup(value, is_cleanup as i32);
+        }
+
+        Ok(BasicValueEnum::new(value))
+    }
+
+    /// Builds a resume instruction, which resumes propagation of an in-flight exception
+    /// caught by a landing pad.
+    pub fn build_resume<V: BasicValue<'ctx>>(
+        &self,
+        exception: V,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
+        let value = unsafe { LLVMBuildResume(self.builder, exception.as_value_ref()) };
+
+        Ok(InstructionValue::new(value))
+    }
+
+    /// Builds a catchswitch instruction, which begins a funclet based exception dispatch by
+    /// transferring control to one of its `handlers`, each of which must start with a catchpad.
+    /// A `parent_pad` of `None` means the catchswitch is not nested within another funclet, and
+    /// an `unwind_block` of `None` unwinds to the caller. Returns `Err` if the builder isn't
+    /// positioned inside of a function.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("seh");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Zero);
+    /// let personality = module.add_function("__CxxFrameHandler3", i32_type.fn_type(&[], true), None);
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let may_throw = module.add_function("may_throw", fn_type, None);
+    /// let fn_value = module.add_function("catches", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let exit = context.append_basic_block(fn_value, "exit");
+    /// let dispatch = context.append_basic_block(fn_value, "dispatch");
+    /// let handler = context.append_basic_block(fn_value, "handler");
+    ///
+    /// fn_value.set_personality_function(personality);
+    /// builder.position_at_end(entry).unwrap();
+    /// builder.build_invoke(may_throw, &[], exit, dispatch, "call").unwrap();
+    /// builder.position_at_end(exit).unwrap();
+    /// builder.build_return(None).unwrap();
+    ///
+    /// builder.position_at_end(dispatch).unwrap();
+    ///
+    /// let catch_switch = builder.build_catch_switch(None, None, &[handler], "switch").unwrap();
+    ///
+    /// builder.position_at_end(handler).unwrap();
+    ///
+    /// let catch_args = [i8_ptr_type.const_null().into(), i32_type.const_int(64, false).into(), i8_ptr_type.const_null().into()];
+    /// let catch_pad = builder.build_catch_pad(catch_switch, &catch_args, "catch").unwrap();
+    ///
+    /// builder.build_catch_ret(catch_pad, exit).unwrap();
+    /// ```
+    #[llvm_versions(6.0..=latest)]
+    pub fn build_catch_switch(
+        &self,
+        parent_pad: Option<InstructionValue<'ctx>>,
+        unwind_block: Option<BasicBlock<'ctx>>,
+        handlers: &[BasicBlock<'ctx>],
+        name: &str,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
+        let parent_pad =
+            match self.get_parent_pad_ref(parent_pad) {
+                Some(parent_pad) => parent_pad,
+                None => return Err(
+                    "The builder must be positioned inside of a function to build a catchswitch.",
+                ),
+            };
+        let unwind_block = unwind_block.map_or(ptr::null_mut(), |block| block.basic_block);
+        let c_string = to_c_str(name);
+
+        let value = unsafe {
+            LLVMBuildCatchSwitch(
+                self.builder,
+                parent_pad,
+                unwind_block,
+                handlers.len() as u32,
+                c_string.as_ptr(),
+            )
+        };
+
+        for handler in handlers {
+            unsafe { LLVMAddHandler(value, handler.basic_block) }
+        }
+
+        Ok(InstructionValue::new(value))
+    }
+
+    /// Builds a catchpad instruction within a `catch_switch`, which must be the first instruction
+    /// of one of its handlers. The meaning of `args` is specific to the personality function.
+    /// Returns `Err` if `catch_switch` is not a catchswitch instruction.
+    #[llvm_versions(6.0..=latest)]
+    pub fn build_catch_pad(
+        &self,
+        catch_switch: InstructionValue<'ctx>,
+        args: &[BasicValueEnum<'ctx>],
+        name: &str,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        if catch_switch.get_opcode() != InstructionOpcode::CatchSwitch {
+            return Err("The parent of a catchpad must be a catchswitch.");
+        }
+
+        let c_string = to_c_str(name);
+        let mut args: Vec<LLVMValueRef> = args.iter().map(|val| val.as_value_ref()).collect();
+
+        let value = unsafe {
+            LLVMBuildCatchPad(
+                self.builder,
+                catch_switch.as_value_ref(),
+                args.as_mut_ptr(),
+                args.len() as u32,
+                c_string.as_ptr(),
+            )
+        };
+
+        Ok(InstructionValue::new(value))
+    }
+
+    /// Builds a cleanuppad instruction, which begins a cleanup funclet. A `parent_pad` of `None`
+    /// means the cleanup is not nested within another funclet. The meaning of `args` is specific
+    /// to the personality function. Returns `Err` if the builder isn't positioned inside of a function.
+    #[llvm_versions(6.0..=latest)]
+    pub fn build_cleanup_pad(
+        &self,
+        parent_pad: Option<InstructionValue<'ctx>>,
+        args: &[BasicValueEnum<'ctx>],
+        name: &str,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        let parent_pad =
+            match self.get_parent_pad_ref(parent_pad) {
+                Some(parent_pad) => parent_pad,
+                None => return Err(
+                    "The builder must be positioned inside of a function to build a cleanuppad.",
+                ),
+            };
+        let c_string = to_c_str(name);
+        let mut args: Vec<LLVMValueRef> = args.iter().map(|val| val.as_value_ref()).collect();
+
+        let value = unsafe {
+            LLVMBuildCleanupPad(
+                self.builder,
+                parent_pad,
+                args.as_mut_ptr(),
+                args.len() as u32,
+                c_string.as_ptr(),
+            )
+        };
+
+        Ok(InstructionValue::new(value))
+    }
+
+    /// Builds a catchret instruction, which ends the catch funclet begun by `catch_pad` and
+    /// continues execution in `block`. Returns `Err` if `catch_pad` is not a catchpad instruction.
+    #[llvm_versions(6.0..=latest)]
+    pub fn build_catch_ret(
+        &self,
+        catch_pad: InstructionValue<'ctx>,
+        block: BasicBlock<'ctx>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        if catch_pad.get_opcode() != InstructionOpcode::CatchPad {
+            return Err("The value passed to build_catch_ret is not a catchpad.");
+        }
+
+        self.check_can_terminate()?;
+
+        let value =
+            unsafe { LLVMBuildCatchRet(self.builder, catch_pad.as_value_ref(), block.basic_block) };
+
+        Ok(InstructionValue::new(value))
+    }
+
+    /// Builds a cleanupret instruction, which ends the cleanup funclet begun by `cleanup_pad`.
+    /// An `unwind_block` of `None` unwinds to the caller. Returns `Err` if `cleanup_pad` is not
+    /// a cleanuppad instruction.
+    #[llvm_versions(6.0..=latest)]
+    pub fn build_cleanup_ret(
+        &self,
+        cleanup_pad: InstructionValue<'ctx>,
+        unwind_block: Option<BasicBlock<'ctx>>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        if cleanup_pad.get_opcode() != InstructionOpcode::CleanupPad {
+            return Err("The value passed to build_cleanup_ret is not a cleanuppad.");
+        }
+
+        self.check_can_terminate()?;
+
+        let unwind_block = unwind_block.map_or(ptr::null_mut(), |block| block.basic_block);
+
+        let value =
+            unsafe { LLVMBuildCleanupRet(self.builder, cleanup_pad.as_value_ref(), unwind_block) };
+
+        Ok(InstructionValue::new(value))
     }
 
     // REVIEW: Doesn't GEP work on array too?
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
+    ///
+    /// Returns `Err` if `ptr` is in a non-default address space and an index into a pointer, array or vector
+    /// isn't as wide as that address space's pointers, according to the module's data layout. This includes
+    /// indexes following a struct field. The pointer size stands in for the address space's index width,
+    /// which the C API doesn't expose, so layouts with a distinct index width aren't checked correctly.
     pub unsafe fn build_gep(
         &self,
         ptr: PointerValue<'ctx>,
         ordered_indexes: &[IntValue<'ctx>],
         name: &str,
-    ) -> PointerValue<'ctx> {
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        if !self.is_gep_index_width_ok(ptr, ordered_indexes) {
+            return Err(
+                "GEP indexes into a non-default address space must be as wide as its pointers.",
+            );
+        }
+
         let c_string = to_c_str(name);
 
         let mut index_values: Vec<LLVMValueRef> = ordered_indexes
@@ -386,18 +1062,31 @@ impl<'ctx> Builder<'ctx> {
             c_string.as_ptr(),
         );
 
-        PointerValue::new(value)
+        Ok(PointerValue::new(value))
     }
 
     // REVIEW: Doesn't GEP work on array too?
     // REVIEW: This could be merge in with build_gep via a in_bounds: bool param
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
+    ///
+    /// Returns `Err` if `ptr` is in a non-default address space and an index into a pointer, array or vector
+    /// isn't as wide as that address space's pointers, according to the module's data layout. This includes
+    /// indexes following a struct field. The pointer size stands in for the address space's index width,
+    /// which the C API doesn't expose, so layouts with a distinct index width aren't checked correctly.
     pub unsafe fn build_in_bounds_gep(
         &self,
         ptr: PointerValue<'ctx>,
         ordered_indexes: &[IntValue<'ctx>],
         name: &str,
-    ) -> PointerValue<'ctx> {
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        if !self.is_gep_index_width_ok(ptr, ordered_indexes) {
+            return Err(
+                "GEP indexes into a non-default address space must be as wide as its pointers.",
+            );
+        }
+
         let c_string = to_c_str(name);
 
         let mut index_values: Vec<LLVMValueRef> = ordered_indexes
@@ -412,10 +1101,10 @@ impl<'ctx> Builder<'ctx> {
             c_string.as_ptr(),
         );
 
-        PointerValue::new(value)
+        Ok(PointerValue::new(value))
     }
 
-    /// Builds a GEP instruction on a struct pointer. Returns `Err(())` if input `PointerValue` doesn't
+    /// Builds a GEP instruction on a struct pointer. Returns `Err` if input `PointerValue` doesn't
     /// point to a struct or if index is out of bounds.
     ///
     /// # Example
@@ -437,7 +1126,7 @@ impl<'ctx> Builder<'ctx> {
     /// let fn_value = module.add_function("", fn_type, None);
     /// let entry = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     ///
     /// let i32_ptr = fn_value.get_first_param().unwrap().into_pointer_value();
     /// let struct_ptr = fn_value.get_last_param().unwrap().into_pointer_value();
@@ -453,18 +1142,20 @@ impl<'ctx> Builder<'ctx> {
         ptr: PointerValue<'ctx>,
         index: u32,
         name: &str,
-    ) -> Result<PointerValue<'ctx>, ()> {
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let ptr_ty = ptr.get_type();
         let pointee_ty = ptr_ty.get_element_type();
 
         if !pointee_ty.is_struct_type() {
-            return Err(());
+            return Err("The pointer passed to build_struct_gep doesn't point to a struct.");
         }
 
         let struct_ty = pointee_ty.into_struct_type();
 
         if index >= struct_ty.count_fields() {
-            return Err(());
+            return Err("The index passed to build_struct_gep is out of bounds for the struct.");
         }
 
         let c_string = to_c_str(name);
@@ -496,16 +1187,18 @@ impl<'ctx> Builder<'ctx> {
     /// let i32_ptr_param1 = fn_value.get_first_param().unwrap().into_pointer_value();
     /// let i32_ptr_param2 = fn_value.get_nth_param(1).unwrap().into_pointer_value();
     ///
-    /// builder.position_at_end(entry);
-    /// builder.build_ptr_diff(i32_ptr_param1, i32_ptr_param2, "diff");
-    /// builder.build_return(None);
+    /// builder.position_at_end(entry).unwrap();
+    /// builder.build_ptr_diff(i32_ptr_param1, i32_ptr_param2, "diff").unwrap();
+    /// builder.build_return(None).unwrap();
     /// ```
     pub fn build_ptr_diff(
         &self,
         lhs_ptr: PointerValue<'ctx>,
         rhs_ptr: PointerValue<'ctx>,
         name: &str,
-    ) -> IntValue<'ctx> {
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -517,7 +1210,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        IntValue::new(value)
+        Ok(IntValue::new(value))
     }
 
     // SubTypes: Maybe this should return PhiValue<T>? That way we could force incoming values to be of T::Value?
@@ -525,12 +1218,18 @@ impl<'ctx> Builder<'ctx> {
     // tricky with VoidType since it has no instance value?
     // TODOC: Phi Instruction(s) must be first instruction(s) in a BasicBlock.
     // REVIEW: Not sure if we can enforce the above somehow via types.
-    pub fn build_phi<T: BasicType<'ctx>>(&self, type_: T, name: &str) -> PhiValue<'ctx> {
+    pub fn build_phi<T: BasicType<'ctx>>(
+        &self,
+        type_: T,
+        name: &str,
+    ) -> Result<PhiValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe { LLVMBuildPhi(self.builder, type_.as_type_ref(), c_string.as_ptr()) };
 
-        PhiValue::new(value)
+        Ok(PhiValue::new(value))
     }
 
     /// Builds a store instruction. It allows you to store a value of type `T` in a pointer to a type `T`.
@@ -554,19 +1253,21 @@ impl<'ctx> Builder<'ctx> {
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_ptr_param = fn_value.get_first_param().unwrap().into_pointer_value();
     ///
-    /// builder.position_at_end(entry);
-    /// builder.build_store(i32_ptr_param, i32_seven);
-    /// builder.build_return(None);
+    /// builder.position_at_end(entry).unwrap();
+    /// builder.build_store(i32_ptr_param, i32_seven).unwrap();
+    /// builder.build_return(None).unwrap();
     /// ```
     pub fn build_store<V: BasicValue<'ctx>>(
         &self,
         ptr: PointerValue<'ctx>,
         value: V,
-    ) -> InstructionValue<'ctx> {
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let value =
             unsafe { LLVMBuildStore(self.builder, value.as_value_ref(), ptr.as_value_ref()) };
 
-        InstructionValue::new(value)
+        Ok(InstructionValue::new(value))
     }
 
     /// Builds a load instruction. It allows you to retrieve a value of type `T` from a pointer to a type `T`.
@@ -588,27 +1289,39 @@ impl<'ctx> Builder<'ctx> {
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_ptr_param = fn_value.get_first_param().unwrap().into_pointer_value();
     ///
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     ///
-    /// let pointee = builder.build_load(i32_ptr_param, "load");
+    /// let pointee = builder.build_load(i32_ptr_param, "load").unwrap();
     ///
-    /// builder.build_return(Some(&pointee));
+    /// builder.build_return(Some(&pointee)).unwrap();
     /// ```
-    pub fn build_load(&self, ptr: PointerValue<'ctx>, name: &str) -> BasicValueEnum<'ctx> {
+    pub fn build_load(
+        &self,
+        ptr: PointerValue<'ctx>,
+        name: &str,
+    ) -> Result<BasicValueEnum<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe { LLVMBuildLoad(self.builder, ptr.as_value_ref(), c_string.as_ptr()) };
 
-        BasicValueEnum::new(value)
+        Ok(BasicValueEnum::new(value))
     }
 
     // TODOC: Stack allocation
-    pub fn build_alloca<T: BasicType<'ctx>>(&self, ty: T, name: &str) -> PointerValue<'ctx> {
+    pub fn build_alloca<T: BasicType<'ctx>>(
+        &self,
+        ty: T,
+        name: &str,
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe { LLVMBuildAlloca(self.builder, ty.as_type_ref(), c_string.as_ptr()) };
 
-        PointerValue::new(value)
+        Ok(PointerValue::new(value))
     }
 
     // TODOC: Stack allocation
@@ -617,7 +1330,9 @@ impl<'ctx> Builder<'ctx> {
         ty: T,
         size: IntValue<'ctx>,
         name: &str,
-    ) -> PointerValue<'ctx> {
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -629,7 +1344,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        PointerValue::new(value)
+        Ok(PointerValue::new(value))
     }
 
     /// Build a [memcpy](https://llvm.org/docs/LangRef.html#llvm-memcpy-intrinsic) instruction.
@@ -649,6 +1364,8 @@ impl<'ctx> Builder<'ctx> {
         src_align_bytes: u32,
         size: IntValue<'ctx>,
     ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         if !is_alignment_ok(src_align_bytes) {
             return Err("The src_align_bytes argument to build_memcpy was not a power of 2.");
         }
@@ -688,6 +1405,8 @@ impl<'ctx> Builder<'ctx> {
         src_align_bytes: u32,
         size: IntValue<'ctx>,
     ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         if !is_alignment_ok(src_align_bytes) {
             return Err(
                 "The src_align_bytes argument to build_memmove was not a power of 2 under 2^64.",
@@ -720,6 +1439,8 @@ impl<'ctx> Builder<'ctx> {
         ty: T,
         name: &str,
     ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         // LLVMBulidMalloc segfaults if ty is unsized
         if !ty.is_sized() {
             return Err("Cannot build malloc call for an unsized type");
@@ -739,6 +1460,8 @@ impl<'ctx> Builder<'ctx> {
         size: IntValue<'ctx>,
         name: &str,
     ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         // LLVMBulidArrayMalloc segfaults if ty is unsized
         if !ty.is_sized() {
             return Err("Cannot build array malloc call for an unsized type");
@@ -759,13 +1482,24 @@ impl<'ctx> Builder<'ctx> {
     }
 
     // SubType: <P>(&self, ptr: PointerValue<P>) -> InstructionValue {
-    pub fn build_free(&self, ptr: PointerValue<'ctx>) -> InstructionValue<'ctx> {
+    pub fn build_free(
+        &self,
+        ptr: PointerValue<'ctx>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let val = unsafe { LLVMBuildFree(self.builder, ptr.as_value_ref()) };
 
-        InstructionValue::new(val)
+        Ok(InstructionValue::new(val))
     }
 
-    pub fn insert_instruction(&self, instruction: &InstructionValue<'ctx>, name: Option<&str>) {
+    pub fn insert_instruction(
+        &self,
+        instruction: &InstructionValue<'ctx>,
+        name: Option<&str>,
+    ) -> Result<(), &'static str> {
+        self.check_can_build()?;
+
         match name {
             Some(name) => {
                 let c_string = to_c_str(name);
@@ -782,6 +1516,8 @@ impl<'ctx> Builder<'ctx> {
                 LLVMInsertIntoBuilder(self.builder, instruction.as_value_ref());
             },
         }
+
+        Ok(())
     }
 
     pub fn get_insert_block(&self) -> Option<BasicBlock<'ctx>> {
@@ -793,7 +1529,14 @@ impl<'ctx> Builder<'ctx> {
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
     // SubType: <I: IntSubType>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
     //     if I::sign() == Unsigned { LLVMBuildUDiv() } else { LLVMBuildSDiv() }
-    pub fn build_int_unsigned_div<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_unsigned_div<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -805,12 +1548,19 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_signed_div<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_signed_div<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -822,7 +1572,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
@@ -832,7 +1582,9 @@ impl<'ctx> Builder<'ctx> {
         lhs: T,
         rhs: T,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -844,12 +1596,19 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_unsigned_rem<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_unsigned_rem<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -861,12 +1620,19 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // TODO: Possibly make this generic over sign via struct metadata or subtypes
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_signed_rem<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_signed_rem<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -878,7 +1644,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     pub fn build_int_s_extend<T: IntMathValue<'ctx>>(
@@ -886,7 +1652,9 @@ impl<'ctx> Builder<'ctx> {
         int_value: T,
         int_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -898,16 +1666,104 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
+    }
+
+    /// Builds a call to the `llvm.ptrmask` intrinsic, which masks off bits of a pointer's address
+    /// like a ptrtoint, and, inttoptr sequence would, but without losing track of the underlying object.
+    /// Returns `Err` if the builder isn't positioned inside of a function, or if the module has a data
+    /// layout and the width of `mask` doesn't match the size of pointers in `ptr`'s address space.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("ptrmask");
+    /// let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Zero);
+    /// let i64_type = context.i64_type();
+    /// let fn_type = i8_ptr_type.fn_type(&[i8_ptr_type.into()], false);
+    /// let fn_value = module.add_function("align_down", fn_type, None);
+    /// let builder = context.create_builder();
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// let mask = i64_type.const_int(!0xF, false);
+    /// let aligned_ptr = builder.build_ptrmask(ptr, mask, "aligned_ptr").unwrap();
+    ///
+    /// builder.build_return(Some(&aligned_ptr)).unwrap();
+    /// ```
+    #[llvm_versions(10.0..=latest)]
+    pub fn build_ptrmask(
+        &self,
+        ptr: PointerValue<'ctx>,
+        mask: IntValue<'ctx>,
+        name: &str,
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        let ptr_type = ptr.get_type().as_type_ref();
+        let mask_type = mask.get_type().as_type_ref();
+
+        if !self.is_ptr_int_width_ok(mask_type, ptr_type) {
+            return Err(
+                "The mask must be as wide as pointers in the address space of the pointer.",
+            );
+        }
+
+        self.build_intrinsic_call(
+            "llvm.ptrmask",
+            &[ptr_type, mask_type],
+            &[ptr.as_value_ref(), mask.as_value_ref()],
+            name,
+        )
+        .map(PointerValue::new)
     }
 
+    /// Builds an address space cast instruction, which casts a pointer from one
+    /// address space to another. Returns `Err` if both pointer types share the
+    /// same address space, as a bitcast should be used instead in that case.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("av");
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let generic_ptr_type = i32_type.ptr_type(AddressSpace::Zero);
+    /// let global_ptr_type = i32_type.ptr_type(AddressSpace::One);
+    /// let fn_type = void_type.fn_type(&[generic_ptr_type.into()], false);
+    /// let fn_value = module.add_function("cast_fn", fn_type, None);
+    /// let builder = context.create_builder();
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let ptr_param = fn_value.get_first_param().unwrap().into_pointer_value();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// assert!(builder.build_address_space_cast(ptr_param, global_ptr_type, "cast").is_ok());
+    /// assert!(builder.build_address_space_cast(ptr_param, generic_ptr_type, "cast").is_err());
+    ///
+    /// builder.build_return(None).unwrap();
+    /// ```
     // REVIEW: Does this need vector support?
     pub fn build_address_space_cast(
         &self,
         ptr_val: PointerValue<'ctx>,
         ptr_type: PointerType<'ctx>,
         name: &str,
-    ) -> PointerValue<'ctx> {
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        if ptr_val.get_type().get_address_space() == ptr_type.get_address_space() {
+            return Err("The pointer passed to build_address_space_cast is already in the destination address space.");
+        }
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -919,12 +1775,17 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        PointerValue::new(value)
+        Ok(PointerValue::new(value))
     }
 
     /// Builds a bitcast instruction. A bitcast reinterprets the bits of one value
     /// into a value of another type which has the same bit width.
     ///
+    /// Returns an error rather than building invalid IR when either type is a struct or
+    /// an array, when casting between a pointer and a non-pointer, when casting pointers
+    /// across address spaces, or when the types have differing bit widths. Casts involving
+    /// scalable vectors aren't width checked, as their size is only known at runtime.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -942,18 +1803,31 @@ impl<'ctx> Builder<'ctx> {
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_arg = fn_value.get_first_param().unwrap();
     ///
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     ///
-    /// builder.build_bitcast(i32_arg, f32_type, "i32tof32");
-    /// builder.build_return(None);
+    /// builder.build_bitcast(i32_arg, f32_type, "i32tof32").unwrap();
+    /// builder.build_return(None).unwrap();
     ///
     /// assert!(module.verify().is_ok());
     /// ```
-    pub fn build_bitcast<T, V>(&self, val: V, ty: T, name: &str) -> BasicValueEnum<'ctx>
+    pub fn build_bitcast<T, V>(
+        &self,
+        val: V,
+        ty: T,
+        name: &str,
+    ) -> Result<BasicValueEnum<'ctx>, &'static str>
     where
         T: BasicType<'ctx>,
         V: BasicValue<'ctx>,
     {
+        self.check_can_build()?;
+
+        if let Some(err) =
+            unsafe { get_bitcast_error(LLVMTypeOf(val.as_value_ref()), ty.as_type_ref()) }
+        {
+            return Err(err);
+        }
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -965,7 +1839,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        BasicValueEnum::new(value)
+        Ok(BasicValueEnum::new(value))
     }
 
     pub fn build_int_s_extend_or_bit_cast<T: IntMathValue<'ctx>>(
@@ -973,7 +1847,9 @@ impl<'ctx> Builder<'ctx> {
         int_value: T,
         int_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -985,7 +1861,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     pub fn build_int_z_extend<T: IntMathValue<'ctx>>(
@@ -993,7 +1869,9 @@ impl<'ctx> Builder<'ctx> {
         int_value: T,
         int_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1005,7 +1883,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     pub fn build_int_z_extend_or_bit_cast<T: IntMathValue<'ctx>>(
@@ -1013,7 +1891,9 @@ impl<'ctx> Builder<'ctx> {
         int_value: T,
         int_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1025,7 +1905,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     pub fn build_int_truncate<T: IntMathValue<'ctx>>(
@@ -1033,7 +1913,9 @@ impl<'ctx> Builder<'ctx> {
         int_value: T,
         int_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1045,7 +1927,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     pub fn build_int_truncate_or_bit_cast<T: IntMathValue<'ctx>>(
@@ -1053,7 +1935,9 @@ impl<'ctx> Builder<'ctx> {
         int_value: T,
         int_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1065,10 +1949,17 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
-    pub fn build_float_rem<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_float_rem<T: FloatMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1080,7 +1971,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: Consolidate these two casts into one via subtypes
@@ -1089,7 +1980,12 @@ impl<'ctx> Builder<'ctx> {
         float: T,
         int_type: <T::BaseType as FloatMathType<'ctx>>::MathConvType,
         name: &str,
-    ) -> <<T::BaseType as FloatMathType<'ctx>>::MathConvType as IntMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as FloatMathType<'ctx>>::MathConvType as IntMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1101,7 +1997,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        <<T::BaseType as FloatMathType>::MathConvType as IntMathType>::ValueType::new(value)
+        Ok(<<T::BaseType as FloatMathType>::MathConvType as IntMathType>::ValueType::new(value))
     }
 
     pub fn build_float_to_signed_int<T: FloatMathValue<'ctx>>(
@@ -1109,7 +2005,12 @@ impl<'ctx> Builder<'ctx> {
         float: T,
         int_type: <T::BaseType as FloatMathType<'ctx>>::MathConvType,
         name: &str,
-    ) -> <<T::BaseType as FloatMathType<'ctx>>::MathConvType as IntMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as FloatMathType<'ctx>>::MathConvType as IntMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1121,7 +2022,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        <<T::BaseType as FloatMathType>::MathConvType as IntMathType>::ValueType::new(value)
+        Ok(<<T::BaseType as FloatMathType>::MathConvType as IntMathType>::ValueType::new(value))
     }
 
     // REVIEW: Consolidate these two casts into one via subtypes
@@ -1130,7 +2031,12 @@ impl<'ctx> Builder<'ctx> {
         int: T,
         float_type: <T::BaseType as IntMathType<'ctx>>::MathConvType,
         name: &str,
-    ) -> <<T::BaseType as IntMathType<'ctx>>::MathConvType as FloatMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as IntMathType<'ctx>>::MathConvType as FloatMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1142,7 +2048,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        <<T::BaseType as IntMathType>::MathConvType as FloatMathType>::ValueType::new(value)
+        Ok(<<T::BaseType as IntMathType>::MathConvType as FloatMathType>::ValueType::new(value))
     }
 
     pub fn build_signed_int_to_float<T: IntMathValue<'ctx>>(
@@ -1150,7 +2056,12 @@ impl<'ctx> Builder<'ctx> {
         int: T,
         float_type: <T::BaseType as IntMathType<'ctx>>::MathConvType,
         name: &str,
-    ) -> <<T::BaseType as IntMathType<'ctx>>::MathConvType as FloatMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as IntMathType<'ctx>>::MathConvType as FloatMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1162,7 +2073,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        <<T::BaseType as IntMathType>::MathConvType as FloatMathType>::ValueType::new(value)
+        Ok(<<T::BaseType as IntMathType>::MathConvType as FloatMathType>::ValueType::new(value))
     }
 
     pub fn build_float_trunc<T: FloatMathValue<'ctx>>(
@@ -1170,7 +2081,9 @@ impl<'ctx> Builder<'ctx> {
         float: T,
         float_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1182,7 +2095,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     pub fn build_float_ext<T: FloatMathValue<'ctx>>(
@@ -1190,7 +2103,9 @@ impl<'ctx> Builder<'ctx> {
         float: T,
         float_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1202,7 +2117,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     pub fn build_float_cast<T: FloatMathValue<'ctx>>(
@@ -1210,7 +2125,9 @@ impl<'ctx> Builder<'ctx> {
         float: T,
         float_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1222,7 +2139,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <L, R>(&self, lhs: &IntValue<L>, rhs: &IntType<R>, name: &str) -> IntValue<R> {
@@ -1231,7 +2148,9 @@ impl<'ctx> Builder<'ctx> {
         int: T,
         int_type: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1243,10 +2162,17 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
-    pub fn build_float_div<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_float_div<T: FloatMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1258,11 +2184,18 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_add<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_add<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1274,12 +2207,19 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: Possibly incorperate into build_int_add via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_nsw_add<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_nsw_add<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1291,12 +2231,19 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: Possibly incorperate into build_int_add via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_nuw_add<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_nuw_add<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1308,11 +2255,22 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
+    // TODO: FastMathFlags (nnan, ninf, fast, ...) via set_fast_math_flags and build_float_add_fast.
+    // LLVMSetFastMathFlags and LLVMCanValueUseFastMathFlags were only added to the C API in LLVM 18,
+    // so the flags can't be set on instructions for any of the supported versions yet.
+
     // SubType: <F>(&self, lhs: &FloatValue<F>, rhs: &FloatValue<F>, name: &str) -> FloatValue<F> {
-    pub fn build_float_add<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_float_add<T: FloatMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1324,11 +2282,18 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: (&self, lhs: &IntValue<bool>, rhs: &IntValue<bool>, name: &str) -> IntValue<bool> {
-    pub fn build_xor<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_xor<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1340,11 +2305,18 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: (&self, lhs: &IntValue<bool>, rhs: &IntValue<bool>, name: &str) -> IntValue<bool> {
-    pub fn build_and<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_and<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1356,11 +2328,18 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: (&self, lhs: &IntValue<bool>, rhs: &IntValue<bool>, name: &str) -> IntValue<bool> {
-    pub fn build_or<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_or<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1372,7 +2351,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     /// Builds an `IntValue` containing the result of a logical left shift instruction.
@@ -1412,13 +2391,20 @@ impl<'ctx> Builder<'ctx> {
     /// let n = function.get_nth_param(1).unwrap().into_int_value();
     /// let entry_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(entry_block);
+    /// builder.position_at_end(entry_block).unwrap();
     ///
-    /// let shift = builder.build_left_shift(value, n, "left_shift"); // value << n
+    /// let shift = builder.build_left_shift(value, n, "left_shift").unwrap(); // value << n
     ///
-    /// builder.build_return(Some(&shift));
+    /// builder.build_return(Some(&shift)).unwrap();
     /// ```
-    pub fn build_left_shift<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_left_shift<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1430,7 +2416,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     /// Builds an `IntValue` containing the result of a right shift instruction.
@@ -1489,13 +2475,13 @@ impl<'ctx> Builder<'ctx> {
     /// let n = function.get_nth_param(1).unwrap().into_int_value();
     /// let entry_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(entry_block);
+    /// builder.position_at_end(entry_block).unwrap();
     ///
     /// // Whether or not your right shift is sign extended (true) or logical (false) depends
     /// // on the boolean input parameter:
-    /// let shift = builder.build_right_shift(value, n, false, "right_shift"); // value >> n
+    /// let shift = builder.build_right_shift(value, n, false, "right_shift").unwrap(); // value >> n
     ///
-    /// builder.build_return(Some(&shift));
+    /// builder.build_return(Some(&shift)).unwrap();
     /// ```
     pub fn build_right_shift<T: IntMathValue<'ctx>>(
         &self,
@@ -1503,7 +2489,9 @@ impl<'ctx> Builder<'ctx> {
         rhs: T,
         sign_extend: bool,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1524,11 +2512,18 @@ impl<'ctx> Builder<'ctx> {
             }
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_sub<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_sub<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1540,11 +2535,18 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: Possibly incorperate into build_int_sub via flag param
-    pub fn build_int_nsw_sub<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_nsw_sub<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1556,12 +2558,19 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: Possibly incorperate into build_int_sub via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_nuw_sub<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_nuw_sub<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1573,11 +2582,18 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <F>(&self, lhs: &FloatValue<F>, rhs: &FloatValue<F>, name: &str) -> FloatValue<F> {
-    pub fn build_float_sub<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_float_sub<T: FloatMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1589,11 +2605,18 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_mul<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_mul<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1605,12 +2628,19 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: Possibly incorperate into build_int_mul via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_nsw_mul<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_nsw_mul<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1622,12 +2652,19 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: Possibly incorperate into build_int_mul via flag param
     // SubType: <I>(&self, lhs: &IntValue<I>, rhs: &IntValue<I>, name: &str) -> IntValue<I> {
-    pub fn build_int_nuw_mul<T: IntMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_int_nuw_mul<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1639,11 +2676,412 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
+    }
+
+    /// Builds a call to the `llvm.sadd.sat` intrinsic overloaded for the operand type, which
+    /// adds two signed ints and clamps the result to the range of the type instead of wrapping.
+    /// Returns `Err` if the builder isn't positioned inside of a function.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("sat");
+    /// let i8_type = context.i8_type();
+    /// let fn_type = i8_type.fn_type(&[i8_type.into(), i8_type.into()], false);
+    /// let fn_value = module.add_function("sat_add", fn_type, None);
+    /// let builder = context.create_builder();
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let lhs = fn_value.get_first_param().unwrap().into_int_value();
+    /// let rhs = fn_value.get_last_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// let sum = builder.build_sadd_sat(lhs, rhs, "sum").unwrap();
+    ///
+    /// builder.build_return(Some(&sum)).unwrap();
+    /// ```
+    #[llvm_versions(8.0..=latest)]
+    pub fn build_sadd_sat<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.build_binary_intrinsic_call(
+            "llvm.sadd.sat",
+            lhs.as_value_ref(),
+            rhs.as_value_ref(),
+            name,
+        )
+        .map(T::new)
+    }
+
+    /// Builds a call to the `llvm.uadd.sat` intrinsic overloaded for the operand type, which
+    /// adds two unsigned ints and clamps the result to the maximum value of the type.
+    /// Returns `Err` if the builder isn't positioned inside of a function.
+    #[llvm_versions(8.0..=latest)]
+    pub fn build_uadd_sat<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.build_binary_intrinsic_call(
+            "llvm.uadd.sat",
+            lhs.as_value_ref(),
+            rhs.as_value_ref(),
+            name,
+        )
+        .map(T::new)
+    }
+
+    /// Builds a call to the `llvm.ssub.sat` intrinsic overloaded for the operand type, which
+    /// subtracts two signed ints and clamps the result to the range of the type.
+    /// Returns `Err` if the builder isn't positioned inside of a function.
+    #[llvm_versions(8.0..=latest)]
+    pub fn build_ssub_sat<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.build_binary_intrinsic_call(
+            "llvm.ssub.sat",
+            lhs.as_value_ref(),
+            rhs.as_value_ref(),
+            name,
+        )
+        .map(T::new)
+    }
+
+    /// Builds a call to the `llvm.usub.sat` intrinsic overloaded for the operand type, which
+    /// subtracts two unsigned ints and clamps the result at zero.
+    /// Returns `Err` if the builder isn't positioned inside of a function.
+    #[llvm_versions(8.0..=latest)]
+    pub fn build_usub_sat<T: IntMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.build_binary_intrinsic_call(
+            "llvm.usub.sat",
+            lhs.as_value_ref(),
+            rhs.as_value_ref(),
+            name,
+        )
+        .map(T::new)
+    }
+
+    /// Builds a call to the `llvm.sadd.with.overflow` intrinsic overloaded for the operand type.
+    /// The resulting `{ iN, i1 }` struct holds the wrapped sum and whether the signed addition
+    /// overflowed. Returns `Err` if the builder isn't positioned inside of a function.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("overflow");
+    /// let bool_type = context.bool_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = bool_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    /// let fn_value = module.add_function("add_overflows", fn_type, None);
+    /// let builder = context.create_builder();
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let lhs = fn_value.get_first_param().unwrap().into_int_value();
+    /// let rhs = fn_value.get_last_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// let result = builder.build_sadd_with_overflow(lhs, rhs, "result").unwrap();
+    /// let overflowed = builder.build_extract_value(result, 1, "overflowed").unwrap();
+    ///
+    /// builder.build_return(Some(&overflowed)).unwrap();
+    /// ```
+    #[llvm_versions(3.9..=latest)]
+    pub fn build_sadd_with_overflow(
+        &self,
+        lhs: IntValue<'ctx>,
+        rhs: IntValue<'ctx>,
+        name: &str,
+    ) -> Result<StructValue<'ctx>, &'static str> {
+        self.build_binary_intrinsic_call(
+            "llvm.sadd.with.overflow",
+            lhs.as_value_ref(),
+            rhs.as_value_ref(),
+            name,
+        )
+        .map(StructValue::new)
+    }
+
+    /// Builds a call to the `llvm.uadd.with.overflow` intrinsic overloaded for the operand type.
+    /// The resulting `{ iN, i1 }` struct holds the wrapped sum and whether the unsigned addition
+    /// overflowed. Returns `Err` if the builder isn't positioned inside of a function.
+    #[llvm_versions(3.9..=latest)]
+    pub fn build_uadd_with_overflow(
+        &self,
+        lhs: IntValue<'ctx>,
+        rhs: IntValue<'ctx>,
+        name: &str,
+    ) -> Result<StructValue<'ctx>, &'static str> {
+        self.build_binary_intrinsic_call(
+            "llvm.uadd.with.overflow",
+            lhs.as_value_ref(),
+            rhs.as_value_ref(),
+            name,
+        )
+        .map(StructValue::new)
+    }
+
+    /// Builds a call to the `llvm.assume` intrinsic, which lets the optimizer assume `cond` holds.
+    /// Returns `Err` if `cond` isn't an `i1` or the builder isn't positioned inside of a function.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::IntPredicate;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("assume");
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("positive", fn_type, None);
+    /// let builder = context.create_builder();
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let value = fn_value.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// let is_positive = builder.build_int_compare(IntPredicate::SGT, value, i32_type.const_zero(), "is_positive").unwrap();
+    ///
+    /// builder.build_assume(is_positive).unwrap();
+    /// builder.build_return(Some(&value)).unwrap();
+    /// ```
+    pub fn build_assume(
+        &self,
+        cond: IntValue<'ctx>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        if cond.get_type().get_bit_width() != 1 {
+            return Err("The condition passed to build_assume must be an i1.");
+        }
+
+        self.build_intrinsic_call("llvm.assume", &[], &[cond.as_value_ref()], "")
+            .map(InstructionValue::new)
+    }
+
+    /// Builds a call to the `llvm.expect` intrinsic overloaded for the operand type, which
+    /// returns `value` and tells the optimizer it is most likely equal to `expected`.
+    /// Returns `Err` if `expected` isn't a constant of the same type as `value`, or if the
+    /// builder isn't positioned inside of a function.
+    pub fn build_expect(
+        &self,
+        value: IntValue<'ctx>,
+        expected: IntValue<'ctx>,
+        name: &str,
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        if value.get_type() != expected.get_type() {
+            return Err(
+                "The expected value passed to build_expect must be of the same type as the value.",
+            );
+        }
+
+        if !expected.is_const() {
+            return Err("The expected value passed to build_expect must be a constant.");
+        }
+
+        self.build_binary_intrinsic_call(
+            "llvm.expect",
+            value.as_value_ref(),
+            expected.as_value_ref(),
+            name,
+        )
+        .map(IntValue::new)
+    }
+
+    /// Builds a call to the `llvm.lifetime.start` intrinsic overloaded for the pointer type, which
+    /// marks the beginning of the lifetime of the first `size` bytes of the stack object `ptr`.
+    /// A `size` of `u64::MAX` is passed through as `-1`, which LLVM takes to mean the whole object.
+    /// Returns `Err` if the builder isn't positioned inside of a function.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("lifetime");
+    /// let i32_type = context.i32_type();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("scoped", fn_type, None);
+    /// let builder = context.create_builder();
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// let slot = builder.build_alloca(i32_type, "slot").unwrap();
+    ///
+    /// builder.build_lifetime_start(4, slot).unwrap();
+    /// builder.build_store(slot, i32_type.const_zero()).unwrap();
+    /// builder.build_lifetime_end(4, slot).unwrap();
+    /// builder.build_return(None).unwrap();
+    /// ```
+    pub fn build_lifetime_start(
+        &self,
+        size: u64,
+        ptr: PointerValue<'ctx>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.build_lifetime_intrinsic_call("llvm.lifetime.start", size, ptr)
+    }
+
+    /// Builds a call to the `llvm.lifetime.end` intrinsic overloaded for the pointer type, which
+    /// marks the end of the lifetime of the first `size` bytes of the stack object `ptr`.
+    /// As with `build_lifetime_start`, a `size` of `u64::MAX` stands for the whole object.
+    /// Returns `Err` if the builder isn't positioned inside of a function.
+    pub fn build_lifetime_end(
+        &self,
+        size: u64,
+        ptr: PointerValue<'ctx>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.build_lifetime_intrinsic_call("llvm.lifetime.end", size, ptr)
+    }
+
+    /// Builds a call to the `llvm.experimental.vector.reduce.add` intrinsic overloaded for the vector
+    /// type, which adds up all of the elements of an int vector, wrapping on overflow. Returns `Err` if
+    /// `vector` isn't a vector of ints or the builder isn't positioned inside of a function.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("reduce");
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.vec_type(4).into()], false);
+    /// let fn_value = module.add_function("sum", fn_type, None);
+    /// let builder = context.create_builder();
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let vector = fn_value.get_first_param().unwrap().into_vector_value();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// let sum = builder.build_vector_reduce_add(vector, "sum").unwrap();
+    ///
+    /// builder.build_return(Some(&sum)).unwrap();
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_vector_reduce_add(
+        &self,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        self.build_int_vector_reduce_call("llvm.experimental.vector.reduce.add", vector, name)
+    }
+
+    /// Builds a call to the `llvm.experimental.vector.reduce.mul` intrinsic overloaded for the vector
+    /// type, which multiplies all of the elements of an int vector together. Returns `Err` if `vector`
+    /// isn't a vector of ints or the builder isn't positioned inside of a function.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_vector_reduce_mul(
+        &self,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        self.build_int_vector_reduce_call("llvm.experimental.vector.reduce.mul", vector, name)
+    }
+
+    /// Builds a call to the `llvm.experimental.vector.reduce.and` intrinsic overloaded for the vector
+    /// type, which ands all of the elements of an int vector together. Returns `Err` if `vector`
+    /// isn't a vector of ints or the builder isn't positioned inside of a function.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_vector_reduce_and(
+        &self,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        self.build_int_vector_reduce_call("llvm.experimental.vector.reduce.and", vector, name)
+    }
+
+    /// Builds a call to the `llvm.experimental.vector.reduce.or` intrinsic overloaded for the vector
+    /// type, which ors all of the elements of an int vector together. Returns `Err` if `vector`
+    /// isn't a vector of ints or the builder isn't positioned inside of a function.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_vector_reduce_or(
+        &self,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        self.build_int_vector_reduce_call("llvm.experimental.vector.reduce.or", vector, name)
+    }
+
+    /// Builds a call to the `llvm.experimental.vector.reduce.xor` intrinsic overloaded for the vector
+    /// type, which xors all of the elements of an int vector together. Returns `Err` if `vector`
+    /// isn't a vector of ints or the builder isn't positioned inside of a function.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_vector_reduce_xor(
+        &self,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        self.build_int_vector_reduce_call("llvm.experimental.vector.reduce.xor", vector, name)
+    }
+
+    /// Builds a call to the `llvm.experimental.vector.reduce.v2.fadd` intrinsic overloaded for the
+    /// scalar and vector types, which adds `start` and all of the elements of a float vector
+    /// in order. Returns `Err` if `vector` isn't a vector of `start`'s type or the builder isn't
+    /// positioned inside of a function.
+    // TODO: Unordered reductions, which need the reassoc fast-math flag set on the call. See the
+    // FastMathFlags note above build_float_add.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_vector_reduce_fadd(
+        &self,
+        start: FloatValue<'ctx>,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<FloatValue<'ctx>, &'static str> {
+        self.build_float_vector_reduce_call(
+            "llvm.experimental.vector.reduce.v2.fadd",
+            start,
+            vector,
+            name,
+        )
+    }
+
+    /// Builds a call to the `llvm.experimental.vector.reduce.v2.fmul` intrinsic overloaded for the
+    /// scalar and vector types, which multiplies `start` and all of the elements of a float vector
+    /// in order. Returns `Err` if `vector` isn't a vector of `start`'s type or the builder isn't
+    /// positioned inside of a function.
+    #[llvm_versions(9.0..=latest)]
+    pub fn build_vector_reduce_fmul(
+        &self,
+        start: FloatValue<'ctx>,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<FloatValue<'ctx>, &'static str> {
+        self.build_float_vector_reduce_call(
+            "llvm.experimental.vector.reduce.v2.fmul",
+            start,
+            vector,
+            name,
+        )
     }
 
     // SubType: <F>(&self, lhs: &FloatValue<F>, rhs: &FloatValue<F>, name: &str) -> FloatValue<F> {
-    pub fn build_float_mul<T: FloatMathValue<'ctx>>(&self, lhs: T, rhs: T, name: &str) -> T {
+    pub fn build_float_mul<T: FloatMathValue<'ctx>>(
+        &self,
+        lhs: T,
+        rhs: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1655,7 +3093,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     pub fn build_cast<T: BasicType<'ctx>, V: BasicValue<'ctx>>(
@@ -1664,7 +3102,9 @@ impl<'ctx> Builder<'ctx> {
         from_value: V,
         to_type: T,
         name: &str,
-    ) -> BasicValueEnum<'ctx> {
+    ) -> Result<BasicValueEnum<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1677,7 +3117,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        BasicValueEnum::new(value)
+        Ok(BasicValueEnum::new(value))
     }
 
     // SubType: <F, T>(&self, from: &PointerValue<F>, to: &PointerType<T>, name: &str) -> PointerValue<T> {
@@ -1686,7 +3126,9 @@ impl<'ctx> Builder<'ctx> {
         from: T,
         to: T::BaseType,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1698,7 +3140,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <I>(&self, op, lhs: &IntValue<I>, rhs: &IntValue<I>, name) -> IntValue<bool> { ?
@@ -1711,7 +3153,9 @@ impl<'ctx> Builder<'ctx> {
         lhs: T,
         rhs: T,
         name: &str,
-    ) -> T {
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1724,7 +3168,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <F>(&self, op, lhs: &FloatValue<F>, rhs: &FloatValue<F>, name) -> IntValue<bool> { ?
@@ -1735,7 +3179,12 @@ impl<'ctx> Builder<'ctx> {
         lhs: T,
         rhs: T,
         name: &str,
-    ) -> <<T::BaseType as FloatMathType<'ctx>>::MathConvType as IntMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as FloatMathType<'ctx>>::MathConvType as IntMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -1748,16 +3197,18 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        <<T::BaseType as FloatMathType>::MathConvType as IntMathType>::ValueType::new(value)
+        Ok(<<T::BaseType as FloatMathType>::MathConvType as IntMathType>::ValueType::new(value))
     }
 
     pub fn build_unconditional_branch(
         &self,
         destination_block: BasicBlock<'ctx>,
-    ) -> InstructionValue<'ctx> {
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
         let value = unsafe { LLVMBuildBr(self.builder, destination_block.basic_block) };
 
-        InstructionValue::new(value)
+        Ok(InstructionValue::new(value))
     }
 
     pub fn build_conditional_branch(
@@ -1765,7 +3216,9 @@ impl<'ctx> Builder<'ctx> {
         comparison: IntValue<'ctx>,
         then_block: BasicBlock<'ctx>,
         else_block: BasicBlock<'ctx>,
-    ) -> InstructionValue<'ctx> {
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
         let value = unsafe {
             LLVMBuildCondBr(
                 self.builder,
@@ -1775,14 +3228,16 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        InstructionValue::new(value)
+        Ok(InstructionValue::new(value))
     }
 
     pub fn build_indirect_branch<BV: BasicValue<'ctx>>(
         &self,
         address: BV,
         destinations: &[BasicBlock<'ctx>],
-    ) -> InstructionValue<'ctx> {
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
         let value = unsafe {
             LLVMBuildIndirectBr(
                 self.builder,
@@ -1795,60 +3250,98 @@ impl<'ctx> Builder<'ctx> {
             unsafe { LLVMAddDestination(value, destination.basic_block) }
         }
 
-        InstructionValue::new(value)
+        Ok(InstructionValue::new(value))
     }
 
     // SubType: <I>(&self, value: &IntValue<I>, name) -> IntValue<I> {
-    pub fn build_int_neg<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> T {
+    pub fn build_int_neg<T: IntMathValue<'ctx>>(
+        &self,
+        value: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe { LLVMBuildNeg(self.builder, value.as_value_ref(), c_string.as_ptr()) };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: Possibly incorperate into build_int_neg via flag and subtypes
     // SubType: <I>(&self, value: &IntValue<I>, name) -> IntValue<I> {
-    pub fn build_int_nsw_neg<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> T {
+    pub fn build_int_nsw_neg<T: IntMathValue<'ctx>>(
+        &self,
+        value: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value =
             unsafe { LLVMBuildNSWNeg(self.builder, value.as_value_ref(), c_string.as_ptr()) };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <I>(&self, value: &IntValue<I>, name) -> IntValue<I> {
-    pub fn build_int_nuw_neg<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> T {
+    pub fn build_int_nuw_neg<T: IntMathValue<'ctx>>(
+        &self,
+        value: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value =
             unsafe { LLVMBuildNUWNeg(self.builder, value.as_value_ref(), c_string.as_ptr()) };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <F>(&self, value: &FloatValue<F>, name) -> FloatValue<F> {
-    pub fn build_float_neg<T: FloatMathValue<'ctx>>(&self, value: T, name: &str) -> T {
+    pub fn build_float_neg<T: FloatMathValue<'ctx>>(
+        &self,
+        value: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe { LLVMBuildFNeg(self.builder, value.as_value_ref(), c_string.as_ptr()) };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // SubType: <I>(&self, value: &IntValue<I>, name) -> IntValue<bool> { ?
-    pub fn build_not<T: IntMathValue<'ctx>>(&self, value: T, name: &str) -> T {
+    pub fn build_not<T: IntMathValue<'ctx>>(
+        &self,
+        value: T,
+        name: &str,
+    ) -> Result<T, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe { LLVMBuildNot(self.builder, value.as_value_ref(), c_string.as_ptr()) };
 
-        T::new(value)
+        Ok(T::new(value))
     }
 
     // REVIEW: What if instruction and basic_block are completely unrelated?
     // It'd be great if we could get the BB from the instruction behind the scenes
-    pub fn position_at(&self, basic_block: BasicBlock<'ctx>, instruction: &InstructionValue<'ctx>) {
+    /// Returns `Err` if terminators are being validated and the builder would leave its current
+    /// block unterminated, see `set_validate_terminators`.
+    pub fn position_at(
+        &self,
+        basic_block: BasicBlock<'ctx>,
+        instruction: &InstructionValue<'ctx>,
+    ) -> Result<(), &'static str> {
+        self.check_can_leave(basic_block.basic_block)?;
+
         unsafe {
             LLVMPositionBuilder(
                 self.builder,
@@ -1856,20 +3349,115 @@ impl<'ctx> Builder<'ctx> {
                 instruction.as_value_ref(),
             )
         }
+
+        self.positioned_at_end.set(false);
+
+        Ok(())
     }
 
-    pub fn position_before(&self, instruction: &InstructionValue<'ctx>) {
+    /// Returns `Err` if terminators are being validated and the builder would leave its current
+    /// block unterminated, see `set_validate_terminators`.
+    pub fn position_before(
+        &self,
+        instruction: &InstructionValue<'ctx>,
+    ) -> Result<(), &'static str> {
+        self.check_can_leave(unsafe { LLVMGetInstructionParent(instruction.as_value_ref()) })?;
+
         unsafe { LLVMPositionBuilderBefore(self.builder, instruction.as_value_ref()) }
+
+        self.positioned_at_end.set(false);
+
+        Ok(())
     }
 
-    pub fn position_at_end(&self, basic_block: BasicBlock<'ctx>) {
+    /// Returns `Err` if terminators are being validated and either `basic_block` is already terminated
+    /// or the builder would leave its current block unterminated, see `set_validate_terminators`.
+    pub fn position_at_end(&self, basic_block: BasicBlock<'ctx>) -> Result<(), &'static str> {
+        if self.validate_terminators.get() && basic_block.get_terminator().is_some() {
+            return Err("The block to position the builder at the end of is already terminated.");
+        }
+
+        self.check_can_leave(basic_block.basic_block)?;
+
         unsafe {
             LLVMPositionBuilderAtEnd(self.builder, basic_block.basic_block);
         }
+
+        self.positioned_at_end.set(true);
+
+        Ok(())
+    }
+
+    /// Builds the `fallback` terminator at the end of the block the builder is positioned in,
+    /// unless that block is already terminated. This is useful for closing off blocks whose
+    /// code may or may not have ended in a terminator, such as the last block of a function.
+    /// If the block is unterminated, the builder is moved to the end of it before building
+    /// `fallback`, so it stays positioned after the new terminator rather than before whatever
+    /// instruction it was positioned at.
+    /// Returns the built terminator, `None` if the block already had one, or `Err` if the
+    /// builder isn't positioned inside of a block or if a `Terminator::Return` value doesn't
+    /// match the return type of the block's function.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::builder::Terminator;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    /// let entry = context.append_basic_block(fn_value, "entry");
+    /// let i32_zero = context.i32_type().const_zero();
+    ///
+    /// builder.position_at_end(entry).unwrap();
+    ///
+    /// assert!(builder.ensure_terminated(Terminator::Return(Some(i32_zero.into()))).is_err());
+    /// assert!(builder.ensure_terminated(Terminator::Return(None)).unwrap().is_some());
+    /// assert!(builder.ensure_terminated(Terminator::Unreachable).unwrap().is_none());
+    /// assert!(module.verify().is_ok());
+    /// ```
+    pub fn ensure_terminated(
+        &self,
+        fallback: Terminator<'ctx>,
+    ) -> Result<Option<InstructionValue<'ctx>>, &'static str> {
+        let block = match self.get_insert_block() {
+            Some(block) => block,
+            None => {
+                return Err("The builder must be positioned inside of a block to terminate it.")
+            }
+        };
+
+        if block.get_terminator().is_some() {
+            return Ok(None);
+        }
+
+        if let (Terminator::Return(value), Some(function)) = (&fallback, block.get_parent()) {
+            let return_type = function.get_type().get_return_type();
+
+            if value.map(|value| value.get_type()) != return_type {
+                return Err("The value to return doesn't match the return type of the function.");
+            }
+        }
+
+        // Any instructions after the insertion point would otherwise follow the terminator
+        self.position_at_end(block)?;
+
+        let terminator = match fallback {
+            Terminator::Unreachable => self.build_unreachable()?,
+            Terminator::Return(value) => {
+                self.build_return(value.as_ref().map(|value| value as &dyn BasicValue<'ctx>))?
+            }
+            Terminator::Branch(destination) => self.build_unconditional_branch(destination)?,
+        };
+
+        Ok(Some(terminator))
     }
 
     /// Builds an extract value instruction which extracts a `BasicValueEnum`
-    /// from a struct or array.
+    /// from a struct or array. Returns `Err` if `index` is out of bounds.
     ///
     /// # Example
     ///
@@ -1888,37 +3476,39 @@ impl<'ctx> Builder<'ctx> {
     /// let builder = context.create_builder();
     /// let entry = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     ///
-    /// let array_alloca = builder.build_alloca(array_type, "array_alloca");
-    /// let array = builder.build_load(array_alloca, "array_load").into_array_value();
+    /// let array_alloca = builder.build_alloca(array_type, "array_alloca").unwrap();
+    /// let array = builder.build_load(array_alloca, "array_load").unwrap().into_array_value();
     /// let const_int1 = i32_type.const_int(2, false);
     /// let const_int2 = i32_type.const_int(5, false);
     /// let const_int3 = i32_type.const_int(6, false);
     ///
-    /// assert!(builder.build_insert_value(array, const_int1, 0, "insert").is_some());
-    /// assert!(builder.build_insert_value(array, const_int2, 1, "insert").is_some());
-    /// assert!(builder.build_insert_value(array, const_int3, 2, "insert").is_some());
-    /// assert!(builder.build_insert_value(array, const_int3, 3, "insert").is_none());
+    /// assert!(builder.build_insert_value(array, const_int1, 0, "insert").is_ok());
+    /// assert!(builder.build_insert_value(array, const_int2, 1, "insert").is_ok());
+    /// assert!(builder.build_insert_value(array, const_int3, 2, "insert").is_ok());
+    /// assert!(builder.build_insert_value(array, const_int3, 3, "insert").is_err());
     ///
     /// assert!(builder.build_extract_value(array, 0, "extract").unwrap().is_int_value());
     /// assert!(builder.build_extract_value(array, 1, "extract").unwrap().is_int_value());
     /// assert!(builder.build_extract_value(array, 2, "extract").unwrap().is_int_value());
-    /// assert!(builder.build_extract_value(array, 3, "extract").is_none());
+    /// assert!(builder.build_extract_value(array, 3, "extract").is_err());
     /// ```
     pub fn build_extract_value<AV: AggregateValue<'ctx>>(
         &self,
         agg: AV,
         index: u32,
         name: &str,
-    ) -> Option<BasicValueEnum<'ctx>> {
+    ) -> Result<BasicValueEnum<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let size = match agg.as_aggregate_value_enum() {
             AggregateValueEnum::ArrayValue(av) => av.get_type().len(),
             AggregateValueEnum::StructValue(sv) => sv.get_type().count_fields(),
         };
 
         if index >= size {
-            return None;
+            return Err("The index is out of bounds for the aggregate value.");
         }
 
         let c_string = to_c_str(name);
@@ -1927,11 +3517,12 @@ impl<'ctx> Builder<'ctx> {
             LLVMBuildExtractValue(self.builder, agg.as_value_ref(), index, c_string.as_ptr())
         };
 
-        Some(BasicValueEnum::new(value))
+        Ok(BasicValueEnum::new(value))
     }
 
     /// Builds an insert value instruction which inserts a `BasicValue` into a struct
-    /// or array and returns the resulting aggregate value.
+    /// or array and returns the resulting aggregate value. Returns `Err` if `index`
+    /// is out of bounds.
     ///
     /// # Example
     ///
@@ -1950,18 +3541,18 @@ impl<'ctx> Builder<'ctx> {
     /// let builder = context.create_builder();
     /// let entry = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     ///
-    /// let array_alloca = builder.build_alloca(array_type, "array_alloca");
-    /// let array = builder.build_load(array_alloca, "array_load").into_array_value();
+    /// let array_alloca = builder.build_alloca(array_type, "array_alloca").unwrap();
+    /// let array = builder.build_load(array_alloca, "array_load").unwrap().into_array_value();
     /// let const_int1 = i32_type.const_int(2, false);
     /// let const_int2 = i32_type.const_int(5, false);
     /// let const_int3 = i32_type.const_int(6, false);
     ///
-    /// assert!(builder.build_insert_value(array, const_int1, 0, "insert").is_some());
-    /// assert!(builder.build_insert_value(array, const_int2, 1, "insert").is_some());
-    /// assert!(builder.build_insert_value(array, const_int3, 2, "insert").is_some());
-    /// assert!(builder.build_insert_value(array, const_int3, 3, "insert").is_none());
+    /// assert!(builder.build_insert_value(array, const_int1, 0, "insert").is_ok());
+    /// assert!(builder.build_insert_value(array, const_int2, 1, "insert").is_ok());
+    /// assert!(builder.build_insert_value(array, const_int3, 2, "insert").is_ok());
+    /// assert!(builder.build_insert_value(array, const_int3, 3, "insert").is_err());
     /// ```
     pub fn build_insert_value<AV, BV>(
         &self,
@@ -1969,18 +3560,20 @@ impl<'ctx> Builder<'ctx> {
         value: BV,
         index: u32,
         name: &str,
-    ) -> Option<AggregateValueEnum<'ctx>>
+    ) -> Result<AggregateValueEnum<'ctx>, &'static str>
     where
         AV: AggregateValue<'ctx>,
         BV: BasicValue<'ctx>,
     {
+        self.check_can_build()?;
+
         let size = match agg.as_aggregate_value_enum() {
             AggregateValueEnum::ArrayValue(av) => av.get_type().len(),
             AggregateValueEnum::StructValue(sv) => sv.get_type().count_fields(),
         };
 
         if index >= size {
-            return None;
+            return Err("The index is out of bounds for the aggregate value.");
         }
 
         let c_string = to_c_str(name);
@@ -1995,11 +3588,13 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        Some(AggregateValueEnum::new(value))
+        Ok(AggregateValueEnum::new(value))
     }
 
     /// Builds an extract element instruction which extracts a `BasicValueEnum`
-    /// from a vector.
+    /// from a vector. Returns `Err` if `index` is a constant which is out of
+    /// bounds for the vector.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -2016,18 +3611,24 @@ impl<'ctx> Builder<'ctx> {
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let vector_param = fn_value.get_first_param().unwrap().into_vector_value();
     ///
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     ///
-    /// let extracted = builder.build_extract_element(vector_param, i32_zero, "insert");
+    /// let extracted = builder.build_extract_element(vector_param, i32_zero, "insert").unwrap();
     ///
-    /// builder.build_return(Some(&extracted));
+    /// builder.build_return(Some(&extracted)).unwrap();
     /// ```
     pub fn build_extract_element(
         &self,
         vector: VectorValue<'ctx>,
         index: IntValue<'ctx>,
         name: &str,
-    ) -> BasicValueEnum<'ctx> {
+    ) -> Result<BasicValueEnum<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        if !is_vector_index_in_bounds(vector, index) {
+            return Err("The index to build_extract_element is out of bounds for the vector.");
+        }
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -2039,11 +3640,12 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        BasicValueEnum::new(value)
+        Ok(BasicValueEnum::new(value))
     }
 
     /// Builds an insert element instruction which inserts a `BasicValue` into a vector
-    /// and returns the resulting vector.
+    /// and returns the resulting vector. Returns `Err` if `index` is a constant which
+    /// is out of bounds for the vector.
     ///
     /// # Example
     ///
@@ -2063,9 +3665,9 @@ impl<'ctx> Builder<'ctx> {
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let vector_param = fn_value.get_first_param().unwrap().into_vector_value();
     ///
-    /// builder.position_at_end(entry);
-    /// builder.build_insert_element(vector_param, i32_seven, i32_zero, "insert");
-    /// builder.build_return(None);
+    /// builder.position_at_end(entry).unwrap();
+    /// builder.build_insert_element(vector_param, i32_seven, i32_zero, "insert").unwrap();
+    /// builder.build_return(None).unwrap();
     /// ```
     pub fn build_insert_element<V: BasicValue<'ctx>>(
         &self,
@@ -2073,7 +3675,13 @@ impl<'ctx> Builder<'ctx> {
         element: V,
         index: IntValue<'ctx>,
         name: &str,
-    ) -> VectorValue<'ctx> {
+    ) -> Result<VectorValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        if !is_vector_index_in_bounds(vector, index) {
+            return Err("The index to build_insert_element is out of bounds for the vector.");
+        }
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -2086,13 +3694,15 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        VectorValue::new(value)
+        Ok(VectorValue::new(value))
     }
 
-    pub fn build_unreachable(&self) -> InstructionValue<'ctx> {
+    pub fn build_unreachable(&self) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
         let val = unsafe { LLVMBuildUnreachable(self.builder) };
 
-        InstructionValue::new(val)
+        Ok(InstructionValue::new(val))
     }
 
     // REVIEW: Not sure if this should return InstructionValue or an actual value
@@ -2102,49 +3712,86 @@ impl<'ctx> Builder<'ctx> {
         atomic_ordering: AtomicOrdering,
         num: i32,
         name: &str,
-    ) -> InstructionValue<'ctx> {
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let val =
             unsafe { LLVMBuildFence(self.builder, atomic_ordering.into(), num, c_string.as_ptr()) };
 
-        InstructionValue::new(val)
+        Ok(InstructionValue::new(val))
     }
 
+    /// Builds a check of whether `ptr` is null, resulting in an `i1` (or a vector of them for a
+    /// vector of pointers). The comparison is always made against the null of `ptr`'s own type,
+    /// so there is no need to build a null pointer or cast `ptr` to an int beforehand.
     // SubType: <P>(&self, ptr: &PointerValue<P>, name) -> IntValue<bool> {
     pub fn build_is_null<T: PointerMathValue<'ctx>>(
         &self,
         ptr: T,
         name: &str,
-    ) -> <<T::BaseType as PointerMathType<'ctx>>::PtrConvType as IntMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as PointerMathType<'ctx>>::PtrConvType as IntMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let val = unsafe { LLVMBuildIsNull(self.builder, ptr.as_value_ref(), c_string.as_ptr()) };
 
-        <<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(val)
+        Ok(<<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(val))
     }
 
+    /// Builds a check of whether `ptr` isn't null, resulting in an `i1` (or a vector of them for a
+    /// vector of pointers). As with `build_is_null`, `ptr` is compared against its own type's null.
     // SubType: <P>(&self, ptr: &PointerValue<P>, name) -> IntValue<bool> {
     pub fn build_is_not_null<T: PointerMathValue<'ctx>>(
         &self,
         ptr: T,
         name: &str,
-    ) -> <<T::BaseType as PointerMathType<'ctx>>::PtrConvType as IntMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as PointerMathType<'ctx>>::PtrConvType as IntMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let val =
             unsafe { LLVMBuildIsNotNull(self.builder, ptr.as_value_ref(), c_string.as_ptr()) };
 
-        <<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(val)
+        Ok(<<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(val))
     }
 
+    /// Builds an int to pointer cast, which truncates or zero extends the int to the size of a pointer
+    /// in the destination address space. Returns `Err` if a vector of ints is cast to a vector of pointers
+    /// of a different length or, when enabled with `set_check_ptr_int_widths`, if the width of the int
+    /// doesn't match the size of the pointers.
     // SubType: <I, P>(&self, int: &IntValue<I>, ptr_type: &PointerType<P>, name) -> PointerValue<P> {
     pub fn build_int_to_ptr<T: IntMathValue<'ctx>>(
         &self,
         int: T,
         ptr_type: <T::BaseType as IntMathType<'ctx>>::PtrConvType,
         name: &str,
-    ) -> <<T::BaseType as IntMathType<'ctx>>::PtrConvType as PointerMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as IntMathType<'ctx>>::PtrConvType as PointerMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
+        let int_type = unsafe { LLVMTypeOf(int.as_value_ref()) };
+
+        if !unsafe { are_lane_counts_equal(int_type, ptr_type.as_type_ref()) } {
+            return Err("The vectors passed to build_int_to_ptr do not have the same length.");
+        }
+        if self.check_ptr_int_widths.get()
+            && !self.is_ptr_int_width_ok(int_type, ptr_type.as_type_ref())
+        {
+            return Err("The int width passed to build_int_to_ptr does not match the pointer size of the address space.");
+        }
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -2156,16 +3803,36 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        <<T::BaseType as IntMathType>::PtrConvType as PointerMathType>::ValueType::new(value)
+        Ok(<<T::BaseType as IntMathType>::PtrConvType as PointerMathType>::ValueType::new(value))
     }
 
+    /// Builds a pointer to int cast, which truncates or zero extends the address to the width of the int.
+    /// Returns `Err` if a vector of pointers is cast to a vector of ints of a different length or, when
+    /// enabled with `set_check_ptr_int_widths`, if the width of the int doesn't match the size of a pointer
+    /// in the source address space.
     // SubType: <I, P>(&self, ptr: &PointerValue<P>, int_type: &IntType<I>, name) -> IntValue<I> {
     pub fn build_ptr_to_int<T: PointerMathValue<'ctx>>(
         &self,
         ptr: T,
         int_type: <T::BaseType as PointerMathType<'ctx>>::PtrConvType,
         name: &str,
-    ) -> <<T::BaseType as PointerMathType<'ctx>>::PtrConvType as IntMathType<'ctx>>::ValueType {
+    ) -> Result<
+        <<T::BaseType as PointerMathType<'ctx>>::PtrConvType as IntMathType<'ctx>>::ValueType,
+        &'static str,
+    > {
+        self.check_can_build()?;
+
+        let ptr_type = unsafe { LLVMTypeOf(ptr.as_value_ref()) };
+
+        if !unsafe { are_lane_counts_equal(int_type.as_type_ref(), ptr_type) } {
+            return Err("The vectors passed to build_ptr_to_int do not have the same length.");
+        }
+        if self.check_ptr_int_widths.get()
+            && !self.is_ptr_int_width_ok(int_type.as_type_ref(), ptr_type)
+        {
+            return Err("The int width passed to build_ptr_to_int does not match the pointer size of the address space.");
+        }
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -2177,11 +3844,293 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        <<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(value)
+        Ok(<<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(value))
+    }
+
+    /// Gets the module the builder is positioned in, if any.
+    fn get_module_ref(&self) -> Option<LLVMModuleRef> {
+        let function = self.get_insert_block()?.get_parent()?;
+
+        Some(unsafe { LLVMGetGlobalParent(function.as_value_ref()) })
+    }
+
+    /// Builds a call to a binary intrinsic overloaded for the type of its operands
+    fn build_binary_intrinsic_call(
+        &self,
+        intrinsic_name: &str,
+        lhs: LLVMValueRef,
+        rhs: LLVMValueRef,
+        name: &str,
+    ) -> Result<LLVMValueRef, &'static str> {
+        let overloaded_types = [unsafe { LLVMTypeOf(lhs) }];
+
+        self.build_intrinsic_call(intrinsic_name, &overloaded_types, &[lhs, rhs], name)
+    }
+
+    /// Builds a call to an int vector reduction intrinsic, which is overloaded for the vector type
+    #[llvm_versions(9.0..=latest)]
+    fn build_int_vector_reduce_call(
+        &self,
+        intrinsic_name: &str,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<IntValue<'ctx>, &'static str> {
+        let vector_type = vector.get_type();
+
+        if !vector_type.get_element_type().is_int_type() {
+            return Err("The vector passed to an int vector reduction must be a vector of ints.");
+        }
+
+        self.build_intrinsic_call(
+            intrinsic_name,
+            &[vector_type.as_type_ref()],
+            &[vector.as_value_ref()],
+            name,
+        )
+        .map(IntValue::new)
+    }
+
+    /// Builds a call to an ordered float vector reduction intrinsic, which is overloaded for the
+    /// scalar and vector types
+    #[llvm_versions(9.0..=latest)]
+    fn build_float_vector_reduce_call(
+        &self,
+        intrinsic_name: &str,
+        start: FloatValue<'ctx>,
+        vector: VectorValue<'ctx>,
+        name: &str,
+    ) -> Result<FloatValue<'ctx>, &'static str> {
+        let start_type = start.get_type();
+        let vector_type = vector.get_type();
+
+        if vector_type.get_element_type() != start_type.as_basic_type_enum() {
+            return Err("The vector passed to a float vector reduction must be a vector of the start value's type.");
+        }
+
+        self.build_intrinsic_call(
+            intrinsic_name,
+            &[start_type.as_type_ref(), vector_type.as_type_ref()],
+            &[start.as_value_ref(), vector.as_value_ref()],
+            name,
+        )
+        .map(FloatValue::new)
+    }
+
+    /// Builds a call to one of the lifetime marker intrinsics, which are overloaded for the pointer type
+    fn build_lifetime_intrinsic_call(
+        &self,
+        intrinsic_name: &str,
+        size: u64,
+        ptr: PointerValue<'ctx>,
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        let ptr_type = ptr.get_type();
+        let context = ptr_type.get_context();
+        // u64::MAX is deliberately reinterpreted as i64 -1 here
+        let size = context.i64_type().const_int(size, false);
+
+        self.build_intrinsic_call(
+            intrinsic_name,
+            &[ptr_type.as_type_ref()],
+            &[size.as_value_ref(), ptr.as_value_ref()],
+            "",
+        )
+        .map(InstructionValue::new)
+    }
+
+    /// Builds a call to an intrinsic, declaring it in the module for the given overloaded types if needed
+    fn build_intrinsic_call(
+        &self,
+        intrinsic_name: &str,
+        overloaded_types: &[LLVMTypeRef],
+        args: &[LLVMValueRef],
+        name: &str,
+    ) -> Result<LLVMValueRef, &'static str> {
+        self.check_can_build()?;
+
+        let module = match self.get_module_ref() {
+            Some(module) => module,
+            None => return Err(
+                "The builder must be positioned inside of a function to build an intrinsic call.",
+            ),
+        };
+        let c_string = to_c_str(name);
+
+        unsafe {
+            let intrinsic = get_intrinsic_declaration(module, intrinsic_name, overloaded_types)?;
+            let mut args = args.to_vec();
+
+            Ok(LLVMBuildCall(
+                self.builder,
+                intrinsic,
+                args.as_mut_ptr(),
+                args.len() as u32,
+                c_string.as_ptr(),
+            ))
+        }
+    }
+
+    /// Declares the SyncVM intrinsic `llvm.syncvm.<intrinsic>` in the module the builder is positioned in
+    #[cfg(feature = "target-syncvm")]
+    fn get_syncvm_intrinsic(&self, intrinsic: &str) -> Result<FunctionValue<'ctx>, &'static str> {
+        let module = match self.get_module_ref() {
+            Some(module) => module,
+            None => return Err(
+                "The builder must be positioned inside of a function to build an intrinsic call.",
+            ),
+        };
+        let intrinsic_name = format!("llvm.syncvm.{}", intrinsic);
+
+        unsafe {
+            let intrinsic = get_intrinsic_declaration(module, &intrinsic_name, &[])?;
+
+            Ok(FunctionValue::new(intrinsic)
+                .expect("Declaring a known intrinsic should never fail"))
+        }
+    }
+
+    /// Used by build_syncvm_intrinsic_call and build_syncvm_near_call, to check the arguments of both
+    /// intrinsics and near call callees
+    #[cfg(feature = "target-syncvm")]
+    fn are_intrinsic_args_ok(function: FunctionValue<'ctx>, args: &[BasicValueEnum<'ctx>]) -> bool {
+        let fn_type = function.get_type();
+        let param_types = fn_type.get_param_types();
+        let arg_count_ok = if fn_type.is_var_arg() {
+            args.len() >= param_types.len()
+        } else {
+            args.len() == param_types.len()
+        };
+
+        arg_count_ok
+            && args
+                .iter()
+                .zip(param_types.iter())
+                .all(|(arg, param_type)| arg.get_type() == *param_type)
+    }
+
+    /// Gets the given parent pad, or the `none` token when there is no parent funclet.
+    #[llvm_versions(6.0..=latest)]
+    fn get_parent_pad_ref(
+        &self,
+        parent_pad: Option<InstructionValue<'ctx>>,
+    ) -> Option<LLVMValueRef> {
+        if let Some(parent_pad) = parent_pad {
+            return Some(parent_pad.as_value_ref());
+        }
+
+        // The null value of the token type is the `none` token
+        let module = self.get_module_ref()?;
+
+        unsafe {
+            Some(LLVMConstNull(LLVMTokenTypeInContext(LLVMGetModuleContext(
+                module,
+            ))))
+        }
+    }
+
+    /// Gets the size in bytes of pointers in `address_space` according to the data layout of the module
+    /// the builder is positioned in, if a data layout was set on it.
+    #[llvm_versions(3.9..=latest)]
+    fn get_pointer_size(&self, address_space: u32) -> Option<u32> {
+        let module = self.get_module_ref()?;
+
+        if Module::get_borrowed_data_layout(module)
+            .as_str()
+            .to_bytes()
+            .is_empty()
+        {
+            return None;
+        }
+
+        // The module's own parsed layout is queried rather than parsing its string again
+        Some(unsafe { LLVMPointerSizeForAS(LLVMGetModuleDataLayout(module), address_space) })
+    }
+
+    /// Gets the size in bytes of pointers in `address_space` according to the data layout of the module
+    /// the builder is positioned in, if a data layout was set on it.
+    #[llvm_versions(3.6..=3.8)]
+    fn get_pointer_size(&self, address_space: u32) -> Option<u32> {
+        let module = self.get_module_ref()?;
+        let data_layout = Module::get_borrowed_data_layout(module);
+        let data_layout = data_layout.as_str().to_str().ok()?;
+
+        if data_layout.is_empty() {
+            return None;
+        }
+
+        // These versions can't borrow the module's parsed layout, so it has to be parsed again
+        let target_data = TargetData::create(data_layout);
+
+        Some(unsafe { LLVMPointerSizeForAS(target_data.target_data, address_space) })
+    }
+
+    /// Used by build_int_to_ptr, build_ptr_to_int and build_ptrmask
+    fn is_ptr_int_width_ok(&self, int_type: LLVMTypeRef, ptr_type: LLVMTypeRef) -> bool {
+        unsafe {
+            let int_type = get_scalar_type(int_type);
+            let ptr_type = get_scalar_type(ptr_type);
+
+            match self.get_pointer_size(LLVMGetPointerAddressSpace(ptr_type)) {
+                Some(ptr_size) => LLVMGetIntTypeWidth(int_type) == ptr_size * 8,
+                None => true,
+            }
+        }
+    }
+
+    /// Used by build_gep and build_in_bounds_gep. The C API can't query the index width of an address
+    /// space, so it is assumed to be its pointer size, which it is unless the data layout sets it explicitly.
+    fn is_gep_index_width_ok(
+        &self,
+        ptr: PointerValue<'ctx>,
+        ordered_indexes: &[IntValue<'ctx>],
+    ) -> bool {
+        let ptr_type = ptr.get_type().as_type_ref();
+        let address_space = unsafe { LLVMGetPointerAddressSpace(ptr_type) };
+
+        if address_space == 0 {
+            return true;
+        }
+
+        let index_width = match self.get_pointer_size(address_space) {
+            Some(ptr_size) => ptr_size * 8,
+            None => return true,
+        };
+        let mut indexed_type = ptr_type;
+
+        for index in ordered_indexes {
+            match unsafe { LLVMGetTypeKind(indexed_type) } {
+                LLVMTypeKind::LLVMPointerTypeKind
+                | LLVMTypeKind::LLVMArrayTypeKind
+                | LLVMTypeKind::LLVMVectorTypeKind => {
+                    if index.get_type().get_bit_width() != index_width {
+                        return false;
+                    }
+
+                    indexed_type = unsafe { LLVMGetElementType(indexed_type) };
+                }
+                // Struct fields are always indexed by i32 constants regardless of the address space,
+                // so only the field's type is needed to keep checking the indexes after it
+                LLVMTypeKind::LLVMStructTypeKind => {
+                    let field_type = index
+                        .get_zero_extended_constant()
+                        .and_then(|field| unsafe { get_struct_field_type(indexed_type, field) });
+
+                    indexed_type = match field_type {
+                        Some(field_type) => field_type,
+                        // Leave invalid struct indexes to LLVM
+                        None => return true,
+                    };
+                }
+                _ => return true,
+            }
+        }
+
+        true
     }
 
     pub fn clear_insertion_position(&self) {
         unsafe { LLVMClearInsertionPosition(self.builder) }
+
+        self.positioned_at_end.set(false);
     }
 
     // REVIEW: Returning InstructionValue is the safe move here; but if the value means something
@@ -2192,7 +4141,9 @@ impl<'ctx> Builder<'ctx> {
         value: IntValue<'ctx>,
         else_block: BasicBlock<'ctx>,
         cases: &[(IntValue<'ctx>, BasicBlock<'ctx>)],
-    ) -> InstructionValue<'ctx> {
+    ) -> Result<InstructionValue<'ctx>, &'static str> {
+        self.check_can_terminate()?;
+
         let switch_value = unsafe {
             LLVMBuildSwitch(
                 self.builder,
@@ -2206,9 +4157,15 @@ impl<'ctx> Builder<'ctx> {
             unsafe { LLVMAddCase(switch_value, value.as_value_ref(), basic_block.basic_block) }
         }
 
-        InstructionValue::new(switch_value)
+        Ok(InstructionValue::new(switch_value))
     }
 
+    /// Builds a select between `then` and `else_`. The condition may either be an `i1`, which picks
+    /// one of the operands as a whole, or a vector of `i1`s, which picks each element separately.
+    /// If the condition and both operands are constants, the select is folded just as
+    /// `IntValue::const_select` would.
+    /// Returns `Err` if the condition isn't an `i1` or a vector of them, or if a vector condition
+    /// doesn't have as many elements as the operand vectors.
     // SubTypes: condition can only be IntValue<bool> or VectorValue<IntValue<Bool>>
     pub fn build_select<BV: BasicValue<'ctx>, IMV: IntMathValue<'ctx>>(
         &self,
@@ -2216,7 +4173,49 @@ impl<'ctx> Builder<'ctx> {
         then: BV,
         else_: BV,
         name: &str,
-    ) -> BasicValueEnum<'ctx> {
+    ) -> Result<BasicValueEnum<'ctx>, &'static str> {
+        self.check_can_build()?;
+
+        let condition_type = unsafe { LLVMTypeOf(condition.as_value_ref()) };
+
+        match unsafe { LLVMGetTypeKind(condition_type) } {
+            LLVMTypeKind::LLVMIntegerTypeKind => {
+                if unsafe { LLVMGetIntTypeWidth(condition_type) } != 1 {
+                    return Err("The condition passed to build_select must be an i1.");
+                }
+            }
+            LLVMTypeKind::LLVMVectorTypeKind => {
+                let element_type = unsafe { LLVMGetElementType(condition_type) };
+                let is_i1 = unsafe {
+                    LLVMGetTypeKind(element_type) == LLVMTypeKind::LLVMIntegerTypeKind
+                        && LLVMGetIntTypeWidth(element_type) == 1
+                };
+
+                if !is_i1 {
+                    return Err("The condition vector passed to build_select must be of i1s.");
+                }
+
+                let condition_size = unsafe { LLVMGetVectorSize(condition_type) };
+
+                for operand in &[then.as_value_ref(), else_.as_value_ref()] {
+                    let operand_type = unsafe { LLVMTypeOf(*operand) };
+                    let is_same_size = unsafe {
+                        LLVMGetTypeKind(operand_type) == LLVMTypeKind::LLVMVectorTypeKind
+                            && LLVMGetVectorSize(operand_type) == condition_size
+                    };
+
+                    if !is_same_size {
+                        return Err("The condition vector passed to build_select must have as many elements as the operands.");
+                    }
+                }
+            }
+            _ => {
+                return Err(
+                    "The condition passed to build_select must be an i1 or a vector of them.",
+                )
+            }
+        }
+
         let c_string = to_c_str(name);
         let value = unsafe {
             LLVMBuildSelect(
@@ -2228,7 +4227,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        BasicValueEnum::new(value)
+        Ok(BasicValueEnum::new(value))
     }
 
     // The unsafety of this function should be fixable with subtypes. See GH #32
@@ -2267,7 +4266,9 @@ impl<'ctx> Builder<'ctx> {
         right: VectorValue<'ctx>,
         mask: VectorValue<'ctx>,
         name: &str,
-    ) -> VectorValue<'ctx> {
+    ) -> Result<VectorValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
         let value = unsafe {
             LLVMBuildShuffleVector(
@@ -2279,7 +4280,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        VectorValue::new(value)
+        Ok(VectorValue::new(value))
     }
 
     // REVIEW: Is return type correct?
@@ -2290,7 +4291,9 @@ impl<'ctx> Builder<'ctx> {
         list: PointerValue<'ctx>,
         type_: BT,
         name: &str,
-    ) -> BasicValueEnum<'ctx> {
+    ) -> Result<BasicValueEnum<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let c_string = to_c_str(name);
 
         let value = unsafe {
@@ -2302,7 +4305,7 @@ impl<'ctx> Builder<'ctx> {
             )
         };
 
-        BasicValueEnum::new(value)
+        Ok(BasicValueEnum::new(value))
     }
 
     /// Builds an atomicrmw instruction. It allows you to atomically modify memory.
@@ -2323,9 +4326,9 @@ impl<'ctx> Builder<'ctx> {
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let i32_ptr_param = fn_value.get_first_param().unwrap().into_pointer_value();
     /// let builder = context.create_builder();
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     /// builder.build_atomicrmw(AtomicRMWBinOp::Add, i32_ptr_param, i32_seven, AtomicOrdering::Unordered);
-    /// builder.build_return(None);
+    /// builder.build_return(None).unwrap();
     /// ```
     // https://llvm.org/docs/LangRef.html#atomicrmw-instruction
     pub fn build_atomicrmw(
@@ -2335,6 +4338,8 @@ impl<'ctx> Builder<'ctx> {
         value: IntValue<'ctx>,
         ordering: AtomicOrdering,
     ) -> Result<IntValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         // TODO: add support for fadd, fsub and xchg on floating point types in LLVM 9+.
 
         // "The type of ‘<value>’ must be an integer type whose bit width is a power of two greater than or equal to eight and less than or equal to a target-specific size limit. The type of the ‘<pointer>’ operand must be a pointer to that type." -- https://releases.llvm.org/3.6.2/docs/LangRef.html#atomicrmw-instruction
@@ -2380,9 +4385,9 @@ impl<'ctx> Builder<'ctx> {
     /// let i32_eight = i32_type.const_int(8, false);
     /// let entry = context.append_basic_block(fn_value, "entry");
     /// let builder = context.create_builder();
-    /// builder.position_at_end(entry);
+    /// builder.position_at_end(entry).unwrap();
     /// builder.build_cmpxchg(i32_ptr_param, i32_seven, i32_eight, AtomicOrdering::AcquireRelease, AtomicOrdering::Monotonic);
-    /// builder.build_return(None);
+    /// builder.build_return(None).unwrap();
     /// ```
     // https://llvm.org/docs/LangRef.html#cmpxchg-instruction
     #[llvm_versions(3.9..=latest)]
@@ -2394,6 +4399,8 @@ impl<'ctx> Builder<'ctx> {
         success: AtomicOrdering,
         failure: AtomicOrdering,
     ) -> Result<StructValue<'ctx>, &'static str> {
+        self.check_can_build()?;
+
         let cmp = cmp.as_basic_value_enum();
         let new = new.as_basic_value_enum();
         if cmp.get_type() != new.get_type() {
@@ -2484,6 +4491,117 @@ fn is_alignment_ok(align: u32) -> bool {
     align > 0 && align.is_power_of_two() && (align as f64).log2() < 64.0
 }
 
+/// Gets the element type of a vector type, or the type itself otherwise
+unsafe fn get_scalar_type(type_: LLVMTypeRef) -> LLVMTypeRef {
+    match LLVMGetTypeKind(type_) {
+        LLVMTypeKind::LLVMVectorTypeKind => LLVMGetElementType(type_),
+        _ => type_,
+    }
+}
+
+/// Gets the bit width of a primitive or fixed vector type. Returns None for pointers,
+/// whose width depends on the target, and scalable vectors, whose width isn't fixed.
+unsafe fn get_primitive_bit_width(type_: LLVMTypeRef) -> Option<u64> {
+    let width = match LLVMGetTypeKind(type_) {
+        LLVMTypeKind::LLVMIntegerTypeKind => LLVMGetIntTypeWidth(type_) as u64,
+        LLVMTypeKind::LLVMHalfTypeKind => 16,
+        #[cfg(feature = "llvm11-0")]
+        LLVMTypeKind::LLVMBFloatTypeKind => 16,
+        LLVMTypeKind::LLVMFloatTypeKind => 32,
+        LLVMTypeKind::LLVMDoubleTypeKind => 64,
+        LLVMTypeKind::LLVMX86_FP80TypeKind => 80,
+        LLVMTypeKind::LLVMFP128TypeKind | LLVMTypeKind::LLVMPPC_FP128TypeKind => 128,
+        LLVMTypeKind::LLVMX86_MMXTypeKind => 64,
+        LLVMTypeKind::LLVMVectorTypeKind => {
+            LLVMGetVectorSize(type_) as u64 * get_primitive_bit_width(LLVMGetElementType(type_))?
+        }
+        _ => return None,
+    };
+
+    Some(width)
+}
+
+/// Used by build_bitcast to reject casts LLVM's verifier would later fail on
+unsafe fn get_bitcast_error(from: LLVMTypeRef, to: LLVMTypeRef) -> Option<&'static str> {
+    let is_aggregate = |type_| match LLVMGetTypeKind(type_) {
+        LLVMTypeKind::LLVMStructTypeKind | LLVMTypeKind::LLVMArrayTypeKind => true,
+        _ => false,
+    };
+
+    if is_aggregate(from) || is_aggregate(to) {
+        return Some("Struct and array types cannot be bitcast; store the value to memory and load it back as the other type instead.");
+    }
+
+    let from_scalar = get_scalar_type(from);
+    let to_scalar = get_scalar_type(to);
+    let from_is_ptr = LLVMGetTypeKind(from_scalar) == LLVMTypeKind::LLVMPointerTypeKind;
+    let to_is_ptr = LLVMGetTypeKind(to_scalar) == LLVMTypeKind::LLVMPointerTypeKind;
+
+    if from_is_ptr != to_is_ptr {
+        return Some("Pointers can only be bitcast to other pointers; use build_ptr_to_int or build_int_to_ptr instead.");
+    }
+
+    if from_is_ptr {
+        if LLVMGetPointerAddressSpace(from_scalar) != LLVMGetPointerAddressSpace(to_scalar) {
+            return Some("Pointers can only be bitcast within the same address space; use build_address_space_cast instead.");
+        }
+
+        let lanes = |type_| match LLVMGetTypeKind(type_) {
+            LLVMTypeKind::LLVMVectorTypeKind => LLVMGetVectorSize(type_),
+            _ => 1,
+        };
+
+        if lanes(from) != lanes(to) {
+            return Some("Vectors of pointers can only be bitcast to vectors of pointers of the same length.");
+        }
+
+        return None;
+    }
+
+    match (get_primitive_bit_width(from), get_primitive_bit_width(to)) {
+        (Some(from_width), Some(to_width)) if from_width != to_width => Some(
+            "Bitcasts require both types to have the same bit width; use build_int_cast or build_float_cast to change widths instead.",
+        ),
+        _ => None,
+    }
+}
+
+/// Used by build_int_to_ptr and build_ptr_to_int, whose operands must either both be scalars or both
+/// be vectors of the same length
+unsafe fn are_lane_counts_equal(lhs: LLVMTypeRef, rhs: LLVMTypeRef) -> bool {
+    let lane_count = |type_| match LLVMGetTypeKind(type_) {
+        LLVMTypeKind::LLVMVectorTypeKind => Some(LLVMGetVectorSize(type_)),
+        _ => None,
+    };
+
+    lane_count(lhs) == lane_count(rhs)
+}
+
+/// Used by is_gep_index_width_ok to step into a struct's field, if it has one at that index
+unsafe fn get_struct_field_type(struct_type: LLVMTypeRef, field: u64) -> Option<LLVMTypeRef> {
+    let count = LLVMCountStructElementTypes(struct_type) as u64;
+
+    if field >= count {
+        return None;
+    }
+
+    let mut field_types: Vec<LLVMTypeRef> = Vec::with_capacity(count as usize);
+
+    LLVMGetStructElementTypes(struct_type, field_types.as_mut_ptr());
+    field_types.set_len(count as usize);
+
+    Some(field_types[field as usize])
+}
+
+/// Used by build_extract_element and build_insert_element
+fn is_vector_index_in_bounds(vector: VectorValue, index: IntValue) -> bool {
+    // Only constant indexes can be checked at build time, the rest is left to LLVM
+    match index.get_zero_extended_constant() {
+        Some(index) => index < vector.get_type().get_size() as u64,
+        None => true,
+    }
+}
+
 impl Drop for Builder<'_> {
     fn drop(&mut self) {
         unsafe {
@@ -10,13 +10,13 @@ use llvm_sys::core::LLVMGetInlineAsm;
 use llvm_sys::core::{
     LLVMAppendBasicBlockInContext, LLVMConstStringInContext, LLVMConstStructInContext,
     LLVMContextCreate, LLVMContextDispose, LLVMContextSetDiagnosticHandler,
-    LLVMCreateBuilderInContext, LLVMDoubleTypeInContext, LLVMFP128TypeInContext,
-    LLVMFloatTypeInContext, LLVMGetGlobalContext, LLVMGetMDKindIDInContext, LLVMHalfTypeInContext,
-    LLVMInsertBasicBlockInContext, LLVMInt16TypeInContext, LLVMInt1TypeInContext,
-    LLVMInt32TypeInContext, LLVMInt64TypeInContext, LLVMInt8TypeInContext, LLVMIntTypeInContext,
-    LLVMMDNodeInContext, LLVMMDStringInContext, LLVMModuleCreateWithNameInContext,
-    LLVMPPCFP128TypeInContext, LLVMStructCreateNamed, LLVMStructTypeInContext,
-    LLVMVoidTypeInContext, LLVMX86FP80TypeInContext,
+    LLVMCreateBuilderInContext, LLVMDisposeModule, LLVMDoubleTypeInContext, LLVMFP128TypeInContext,
+    LLVMFloatTypeInContext, LLVMGetGlobalContext, LLVMGetMDKindIDInContext, LLVMGetTypeByName,
+    LLVMHalfTypeInContext, LLVMInsertBasicBlockInContext, LLVMInt16TypeInContext,
+    LLVMInt1TypeInContext, LLVMInt32TypeInContext, LLVMInt64TypeInContext, LLVMInt8TypeInContext,
+    LLVMIntTypeInContext, LLVMModuleCreateWithNameInContext, LLVMPPCFP128TypeInContext,
+    LLVMStructCreateNamed, LLVMStructTypeInContext, LLVMVoidTypeInContext,
+    LLVMX86FP80TypeInContext,
 };
 #[llvm_versions(3.9..=latest)]
 use llvm_sys::core::{LLVMCreateEnumAttribute, LLVMCreateStringAttribute};
@@ -27,7 +27,7 @@ use once_cell::sync::Lazy;
 use parking_lot::{Mutex, MutexGuard};
 
 #[llvm_versions(3.9..=latest)]
-use crate::attributes::Attribute;
+use crate::attributes::{Attribute, EnumAttributeKind};
 use crate::basic_block::BasicBlock;
 use crate::builder::Builder;
 use crate::memory_buffer::MemoryBuffer;
@@ -49,6 +49,38 @@ use std::ops::Deref;
 use std::ptr;
 use std::thread_local;
 
+/// The bit width of a SyncVM word, which is the type of most of its values.
+#[cfg(feature = "target-syncvm")]
+pub const SYNCVM_WORD_SIZE_IN_BITS: u32 = 256;
+
+/// The bit width of an Ethereum address as used by SyncVM.
+#[cfg(feature = "target-syncvm")]
+pub const SYNCVM_ADDRESS_SIZE_IN_BITS: u32 = 160;
+
+/// The SyncVM address space of the stack.
+#[cfg(feature = "target-syncvm")]
+pub const SYNCVM_ADDRESS_SPACE_STACK: AddressSpace = AddressSpace::Zero;
+
+/// The SyncVM address space of the heap.
+#[cfg(feature = "target-syncvm")]
+pub const SYNCVM_ADDRESS_SPACE_HEAP: AddressSpace = AddressSpace::One;
+
+/// The SyncVM address space of the auxiliary heap.
+#[cfg(feature = "target-syncvm")]
+pub const SYNCVM_ADDRESS_SPACE_HEAP_AUXILIARY: AddressSpace = AddressSpace::Two;
+
+/// The SyncVM address space of generic pointers, such as those to calldata and returndata.
+#[cfg(feature = "target-syncvm")]
+pub const SYNCVM_ADDRESS_SPACE_GENERIC: AddressSpace = AddressSpace::Three;
+
+/// The SyncVM address space of the contract's code.
+#[cfg(feature = "target-syncvm")]
+pub const SYNCVM_ADDRESS_SPACE_CODE: AddressSpace = AddressSpace::Four;
+
+/// The SyncVM address space of the contract's storage.
+#[cfg(feature = "target-syncvm")]
+pub const SYNCVM_ADDRESS_SPACE_STORAGE: AddressSpace = AddressSpace::Five;
+
 // The idea of using a Mutex<Context> here and a thread local'd MutexGuard<Context> in
 // GLOBAL_CTX_LOCK is to ensure two things:
 // 1) Only one thread has access to the global context at a time.
@@ -174,8 +206,8 @@ impl Context {
     /// let fn_val = module.add_function("my_fn", fn_type, None);
     /// let basic_block = context.append_basic_block(fn_val, "entry");
     ///
-    /// builder.position_at_end(basic_block);
-    /// builder.build_return(None);
+    /// builder.position_at_end(basic_block).unwrap();
+    /// builder.build_return(None).unwrap();
     ///
     /// let memory_buffer = module.write_bitcode_to_memory();
     ///
@@ -221,12 +253,12 @@ impl Context {
     /// let fn_val = module.add_function("my_fn", fn_type, None);
     /// let basic_block = context.append_basic_block(fn_val, "entry");
     ///
-    /// builder.position_at_end(basic_block);
+    /// builder.position_at_end(basic_block).unwrap();
     /// let asm_fn = context.i64_type().fn_type(&[context.i64_type().into(), context.i64_type().into()], false);
     /// let asm = context.create_inline_asm(asm_fn, "syscall".to_string(), "=r,{rax},{rdi}".to_string(), true, false, None);
     /// let params = &[context.i64_type().const_int(60, false).into(), context.i64_type().const_int(1, false).into()];
-    /// builder.build_call(asm, params, "exit");
-    /// builder.build_return(None);
+    /// builder.build_call(asm, params, "exit").unwrap();
+    /// builder.build_return(None).unwrap();
     #[llvm_versions(7.0..=latest)]
     pub fn create_inline_asm(
         &self,
@@ -265,12 +297,12 @@ impl Context {
     /// let fn_val = module.add_function("my_fn", fn_type, None);
     /// let basic_block = context.append_basic_block(fn_val, "entry");
     ///
-    /// builder.position_at_end(basic_block);
+    /// builder.position_at_end(basic_block).unwrap();
     /// let asm_fn = context.i64_type().fn_type(&[context.i64_type().into(), context.i64_type().into()], false);
     /// let asm = context.create_inline_asm(asm_fn, "syscall".to_string(), "=r,{rax},{rdi}".to_string(), true, false);
     /// let params = &[context.i64_type().const_int(60, false).into(), context.i64_type().const_int(1, false).into()];
-    /// builder.build_call(asm, params, "exit");
-    /// builder.build_return(None);
+    /// builder.build_call(asm, params, "exit").unwrap();
+    /// builder.build_return(None).unwrap();
     #[llvm_versions(3.6..7.0)]
     pub fn create_inline_asm(
         &self,
@@ -292,7 +324,8 @@ impl Context {
         PointerValue::new(value)
     }
 
-    /// Gets the `VoidType`. It will be assigned the current context.
+    /// Gets the `VoidType`. It will be assigned the current context. LLVM only ever creates
+    /// one `VoidType` per context, so every call returns the same type.
     ///
     /// # Example
     ///
@@ -303,6 +336,7 @@ impl Context {
     /// let void_type = context.void_type();
     ///
     /// assert_eq!(*void_type.get_context(), context);
+    /// assert_eq!(void_type, context.void_type());
     /// ```
     pub fn void_type(&self) -> VoidType {
         let void_type = unsafe { LLVMVoidTypeInContext(self.context) };
@@ -444,6 +478,42 @@ impl Context {
         IntType::new(int_type)
     }
 
+    /// Gets the `IntType` representing a SyncVM word, which is `SYNCVM_WORD_SIZE_IN_BITS` wide.
+    /// It will be assigned the current context.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let word_type = context.syncvm_word_type();
+    ///
+    /// assert_eq!(word_type.get_bit_width(), 256);
+    /// ```
+    #[cfg(feature = "target-syncvm")]
+    pub fn syncvm_word_type(&self) -> IntType {
+        self.custom_width_int_type(SYNCVM_WORD_SIZE_IN_BITS)
+    }
+
+    /// Gets the `IntType` representing an Ethereum address, which is `SYNCVM_ADDRESS_SIZE_IN_BITS`
+    /// wide. It will be assigned the current context.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let address_type = context.syncvm_address_type();
+    ///
+    /// assert_eq!(address_type.get_bit_width(), 160);
+    /// ```
+    #[cfg(feature = "target-syncvm")]
+    pub fn syncvm_address_type(&self) -> IntType {
+        self.custom_width_int_type(SYNCVM_ADDRESS_SIZE_IN_BITS)
+    }
+
     /// Gets the `IntType` representing a bit width of a pointer. It will be assigned the referenced context.
     ///
     /// # Example
@@ -650,6 +720,43 @@ impl Context {
         StructType::new(struct_type)
     }
 
+    /// Gets a named `StructType` from this `Context`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    ///
+    /// assert!(context.get_struct_type("foo").is_none());
+    ///
+    /// let opaque = context.opaque_struct_type("foo");
+    ///
+    /// assert_eq!(context.get_struct_type("foo").unwrap(), opaque);
+    /// ```
+    pub fn get_struct_type(&self, name: &str) -> Option<StructType> {
+        // LLVMGetTypeByName2 is not available until LLVM 12, however LLVMGetTypeByName
+        // already looks the name up in the module's context, so any module will do
+        let c_string = to_c_str(name);
+        let module_name = to_c_str("");
+
+        let struct_type = unsafe {
+            let module = LLVMModuleCreateWithNameInContext(module_name.as_ptr(), self.context);
+            let struct_type = LLVMGetTypeByName(module, c_string.as_ptr());
+
+            LLVMDisposeModule(module);
+
+            struct_type
+        };
+
+        if struct_type.is_null() {
+            return None;
+        }
+
+        Some(StructType::new(struct_type))
+    }
+
     /// Creates a constant `StructValue` from constant values.
     ///
     /// # Example
@@ -802,9 +909,9 @@ impl Context {
     /// let fn_value = module.add_function("my_func", fn_type, None);
     /// let entry_block = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_block);
+    /// builder.position_at_end(entry_block).unwrap();
     ///
-    /// let ret_instr = builder.build_return(None);
+    /// let ret_instr = builder.build_return(None).unwrap();
     ///
     /// assert!(md_node.is_node());
     ///
@@ -813,15 +920,50 @@ impl Context {
     // REVIEW: Maybe more helpful to beginners to call this metadata_tuple?
     // REVIEW: Seems to be unassgned to anything
     pub fn metadata_node(&self, values: &[BasicMetadataValueEnum]) -> MetadataValue {
-        let mut tuple_values: Vec<LLVMValueRef> =
-            values.iter().map(|val| val.as_value_ref()).collect();
+        #[cfg(any(
+            feature = "llvm3-6",
+            feature = "llvm3-7",
+            feature = "llvm3-8",
+            feature = "llvm3-9",
+            feature = "llvm4-0",
+            feature = "llvm5-0",
+            feature = "llvm6-0"
+        ))]
         let metadata_value = unsafe {
+            use llvm_sys::core::LLVMMDNodeInContext;
+
+            let mut tuple_values: Vec<LLVMValueRef> =
+                values.iter().map(|val| val.as_value_ref()).collect();
+
             LLVMMDNodeInContext(
                 self.context,
                 tuple_values.as_mut_ptr(),
                 tuple_values.len() as u32,
             )
         };
+        // Builds a proper MDNode rather than going through the legacy value based API
+        #[cfg(not(any(
+            feature = "llvm3-6",
+            feature = "llvm3-7",
+            feature = "llvm3-8",
+            feature = "llvm3-9",
+            feature = "llvm4-0",
+            feature = "llvm5-0",
+            feature = "llvm6-0"
+        )))]
+        let metadata_value = unsafe {
+            use llvm_sys::core::{LLVMMDNodeInContext2, LLVMMetadataAsValue, LLVMValueAsMetadata};
+            use llvm_sys::prelude::LLVMMetadataRef;
+
+            let mut tuple_values: Vec<LLVMMetadataRef> = values
+                .iter()
+                .map(|val| LLVMValueAsMetadata(val.as_value_ref()))
+                .collect();
+            let metadata =
+                LLVMMDNodeInContext2(self.context, tuple_values.as_mut_ptr(), tuple_values.len());
+
+            LLVMMetadataAsValue(self.context, metadata)
+        };
 
         MetadataValue::new(metadata_value)
     }
@@ -845,9 +987,9 @@ impl Context {
     /// let fn_value = module.add_function("my_func", fn_type, None);
     /// let entry_block = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_block);
+    /// builder.position_at_end(entry_block).unwrap();
     ///
-    /// let ret_instr = builder.build_return(None);
+    /// let ret_instr = builder.build_return(None).unwrap();
     ///
     /// assert!(md_string.is_string());
     ///
@@ -857,8 +999,36 @@ impl Context {
     pub fn metadata_string(&self, string: &str) -> MetadataValue {
         let c_string = to_c_str(string);
 
-        let metadata_value =
-            unsafe { LLVMMDStringInContext(self.context, c_string.as_ptr(), string.len() as u32) };
+        #[cfg(any(
+            feature = "llvm3-6",
+            feature = "llvm3-7",
+            feature = "llvm3-8",
+            feature = "llvm3-9",
+            feature = "llvm4-0",
+            feature = "llvm5-0",
+            feature = "llvm6-0"
+        ))]
+        let metadata_value = unsafe {
+            use llvm_sys::core::LLVMMDStringInContext;
+
+            LLVMMDStringInContext(self.context, c_string.as_ptr(), string.len() as u32)
+        };
+        #[cfg(not(any(
+            feature = "llvm3-6",
+            feature = "llvm3-7",
+            feature = "llvm3-8",
+            feature = "llvm3-9",
+            feature = "llvm4-0",
+            feature = "llvm5-0",
+            feature = "llvm6-0"
+        )))]
+        let metadata_value = unsafe {
+            use llvm_sys::core::{LLVMMDStringInContext2, LLVMMetadataAsValue};
+
+            let metadata = LLVMMDStringInContext2(self.context, c_string.as_ptr(), string.len());
+
+            LLVMMetadataAsValue(self.context, metadata)
+        };
 
         MetadataValue::new(metadata_value)
     }
@@ -920,6 +1090,97 @@ impl Context {
         Attribute::new(attribute)
     }
 
+    /// Creates an enum `Attribute` in this `Context` like `create_enum_attribute`, but returns `Err`
+    /// if `kind_id` isn't a valid enum kind id for the LLVM version being linked against. As kind ids
+    /// shift between LLVM versions, this catches ids hardcoded for a different version.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::Attribute;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let kind_id = Attribute::get_named_enum_kind_id("noalias");
+    ///
+    /// assert!(context.create_enum_attribute_checked(kind_id, 0).is_ok());
+    /// assert!(context.create_enum_attribute_checked(Attribute::get_last_enum_kind_id() + 1, 0).is_err());
+    /// ```
+    #[llvm_versions(3.9..=latest)]
+    pub fn create_enum_attribute_checked(
+        &self,
+        kind_id: u32,
+        val: u64,
+    ) -> Result<Attribute, &'static str> {
+        if kind_id == 0 || kind_id > Attribute::get_last_enum_kind_id() {
+            return Err("Enum attribute kind id is not valid for this LLVM version.");
+        }
+
+        Ok(self.create_enum_attribute(kind_id, val))
+    }
+
+    /// Creates a well known enum `Attribute` in this `Context`, such as `noalias` or `nonnull`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::{Attribute, EnumAttributeKind};
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let noalias_attribute = context.create_named_enum_attribute(EnumAttributeKind::NoAlias);
+    ///
+    /// assert_eq!(noalias_attribute.get_enum_kind_id(), Attribute::get_named_enum_kind_id("noalias"));
+    /// ```
+    #[llvm_versions(3.9..=latest)]
+    pub fn create_named_enum_attribute(&self, kind: EnumAttributeKind) -> Attribute {
+        self.create_enum_attribute(kind.get_kind_id(), 0)
+    }
+
+    /// Creates a `dereferenceable(bytes)` `Attribute` in this `Context`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let dereferenceable_attribute = context.create_dereferenceable_attribute(8);
+    ///
+    /// assert_eq!(dereferenceable_attribute.get_enum_value(), 8);
+    /// ```
+    #[llvm_versions(3.9..=latest)]
+    pub fn create_dereferenceable_attribute(&self, bytes: u64) -> Attribute {
+        let kind_id = Attribute::get_named_enum_kind_id("dereferenceable");
+
+        self.create_enum_attribute(kind_id, bytes)
+    }
+
+    /// Creates an `align(bytes)` `Attribute` in this `Context`. Returns `Err` if the
+    /// alignment isn't a power of two.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let align_attribute = context.create_align_attribute(16).unwrap();
+    ///
+    /// assert_eq!(align_attribute.get_enum_value(), 16);
+    /// assert!(context.create_align_attribute(12).is_err());
+    /// ```
+    #[llvm_versions(3.9..=latest)]
+    pub fn create_align_attribute(&self, bytes: u64) -> Result<Attribute, &'static str> {
+        if !bytes.is_power_of_two() {
+            return Err("The alignment of an align attribute must be a power of two.");
+        }
+
+        let kind_id = Attribute::get_named_enum_kind_id("align");
+
+        Ok(self.create_enum_attribute(kind_id, bytes))
+    }
+
     /// Creates a string `Attribute` in this `Context`.
     ///
     /// # Example
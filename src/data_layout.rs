@@ -1,11 +1,19 @@
+use llvm_sys::target::LLVMCreateTargetData;
+use once_cell::unsync::OnceCell;
+
 use std::ffi::CStr;
 use std::fmt;
 
 use crate::support::{LLVMString, LLVMStringOrRaw};
+use crate::targets::{ByteOrdering, TargetData};
+use crate::types::AnyType;
+use crate::AddressSpace;
 
 #[derive(Eq)]
 pub struct DataLayout {
     pub(crate) data_layout: LLVMStringOrRaw,
+    // Parsed on the first query, so that further queries don't each parse the layout again
+    target_data: OnceCell<TargetData>,
 }
 
 impl DataLayout {
@@ -14,6 +22,7 @@ impl DataLayout {
 
         DataLayout {
             data_layout: LLVMStringOrRaw::Owned(LLVMString::new(data_layout)),
+            target_data: OnceCell::new(),
         }
     }
 
@@ -22,6 +31,7 @@ impl DataLayout {
 
         DataLayout {
             data_layout: LLVMStringOrRaw::Borrowed(data_layout),
+            target_data: OnceCell::new(),
         }
     }
 
@@ -35,6 +45,52 @@ impl DataLayout {
             LLVMStringOrRaw::Borrowed(ptr) => ptr,
         }
     }
+
+    /// Creates a `TargetData` parsed from this layout, so that queries are always answered
+    /// according to this exact layout rather than that of some other target. The query methods
+    /// of `DataLayout` itself share a single `TargetData` which is parsed on first use.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::TargetData;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// module.set_data_layout(&TargetData::create("e-p:64:64").get_data_layout());
+    ///
+    /// let target_data = module.get_data_layout().to_target_data();
+    ///
+    /// assert_eq!(target_data.get_pointer_byte_size(None), 8);
+    /// ```
+    pub fn to_target_data(&self) -> TargetData {
+        let target_data = unsafe { LLVMCreateTargetData(self.as_ptr()) };
+
+        TargetData::new(target_data)
+    }
+
+    /// Gets the size in bytes of a pointer in the given address space, or in the default
+    /// address space for `None`.
+    pub fn get_pointer_size(&self, address_space: Option<AddressSpace>) -> u32 {
+        self.get_target_data().get_pointer_byte_size(address_space)
+    }
+
+    /// Determines whether this layout is little endian, which is LLVM's default when the layout
+    /// doesn't specify an endianness.
+    pub fn is_little_endian(&self) -> bool {
+        self.get_target_data().get_byte_ordering() == ByteOrdering::LittleEndian
+    }
+
+    /// Gets the ABI required alignment in bytes of the given type.
+    pub fn get_abi_alignment(&self, type_: &dyn AnyType) -> u32 {
+        self.get_target_data().get_abi_alignment(type_)
+    }
+
+    fn get_target_data(&self) -> &TargetData {
+        self.target_data.get_or_init(|| self.to_target_data())
+    }
 }
 
 impl PartialEq for DataLayout {
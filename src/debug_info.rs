@@ -617,7 +617,10 @@ impl<'ctx> DebugInfoBuilder<'ctx> {
         }
     }
 
-    /// Create a function type
+    /// Create a function type. The return type comes first in the type array, with `None`
+    /// standing for a `void` return, followed by the types of the parameters in order.
+    // TODO: Take a calling convention once the C API exposes one. LLVMDIBuilderCreateSubroutineType
+    // has no `CC` parameter, so subroutine types are always created with the default (0) one.
     pub fn create_subroutine_type(
         &self,
         file: DIFile<'ctx>,
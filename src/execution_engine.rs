@@ -162,15 +162,15 @@ impl<'ctx> ExecutionEngine<'ctx> {
     /// let f = module.add_function("test_fn", fnt, None);
     /// let b = context.append_basic_block(f, "entry");
     ///
-    /// builder.position_at_end(b);
+    /// builder.position_at_end(b).unwrap();
     ///
     /// let extf = module.add_function("sumf", ft.fn_type(&[ft.into(), ft.into()], false), None);
     ///
     /// let argf = ft.const_float(64.);
-    /// let call_site_value = builder.build_call(extf, &[argf.into(), argf.into()], "retv");
+    /// let call_site_value = builder.build_call(extf, &[argf.into(), argf.into()], "retv").unwrap();
     /// let retv = call_site_value.try_as_basic_value().left().unwrap().into_float_value();
     ///
-    /// builder.build_return(Some(&retv));
+    /// builder.build_return(Some(&retv)).unwrap();
     ///
     /// let mut ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
     /// ee.add_global_mapping(&extf, sumf as usize);
@@ -207,17 +207,41 @@ impl<'ctx> ExecutionEngine<'ctx> {
     /// assert!(ee.add_module(&module).is_err());
     /// ```
     pub fn add_module(&self, module: &Module<'ctx>) -> Result<(), ()> {
-        unsafe { LLVMAddModule(self.execution_engine_inner(), module.module.get()) }
-
         if module.owned_by_ee.borrow().is_some() {
             return Err(());
         }
 
+        unsafe { LLVMAddModule(self.execution_engine_inner(), module.module.get()) }
+
         *module.owned_by_ee.borrow_mut() = Some(self.clone());
 
         Ok(())
     }
 
+    /// Removes a module from an `ExecutionEngine`, handing ownership of it back to the caller
+    /// so that it may be modified, dropped, or added to an `ExecutionEngine` again.
+    ///
+    /// Any function pointers or addresses already looked up from the module, such as with
+    /// `get_function` or `get_function_address`, must not be used once it has been removed.
+    ///
+    /// The method will be `Err` if the module isn't owned by this `ExecutionEngine`.
+    ///
+    /// ```rust,no_run
+    /// use inkwell::targets::{InitializationConfig, Target};
+    /// use inkwell::context::Context;
+    /// use inkwell::OptimizationLevel;
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("test");
+    /// let other_module = context.create_module("other");
+    /// let ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    ///
+    /// assert!(ee.add_module(&other_module).is_ok());
+    /// assert!(ee.remove_module(&other_module).is_ok());
+    /// assert!(ee.add_module(&other_module).is_ok());
+    /// ```
     pub fn remove_module(&self, module: &Module<'ctx>) -> Result<(), RemoveModuleError> {
         match *module.owned_by_ee.borrow() {
             Some(ref ee) if ee.execution_engine_inner() != self.execution_engine_inner() => {
@@ -282,11 +306,11 @@ impl<'ctx> ExecutionEngine<'ctx> {
     /// // Add the function to our module
     /// let f = module.add_function("test_fn", sig, None);
     /// let b = context.append_basic_block(f, "entry");
-    /// builder.position_at_end(b);
+    /// builder.position_at_end(b).unwrap();
     ///
     /// // Insert a return statement
     /// let ret = double.const_float(64.0);
-    /// builder.build_return(Some(&ret));
+    /// builder.build_return(Some(&ret)).unwrap();
     ///
     /// // create the JIT engine
     /// let mut ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
@@ -149,6 +149,83 @@ impl TryFrom<u32> for AddressSpace {
     }
 }
 
+/// Defines the calling conventions known to LLVM by their numeric ids. Target specific conventions
+/// which aren't listed here, such as those of the SyncVM target, can still be used through the
+/// raw `u32` calling convention methods.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum CallConv {
+    /// The default C calling convention.
+    C = 0,
+    /// Makes calls as fast as possible, with no guarantee of compatibility with external callers.
+    Fast = 8,
+    /// Makes calls which are expected to be rarely executed as cheap as possible for the caller.
+    Cold = 9,
+    /// The calling convention of the Glasgow Haskell Compiler.
+    GHC = 10,
+    /// The calling convention of the High-Performance Erlang compiler.
+    HiPE = 11,
+    /// The calling convention of WebKit's JavaScript JIT.
+    WebKitJS = 12,
+    /// Passes arguments in any register, for use with patchpoints and stackmaps.
+    AnyReg = 13,
+    /// Preserves most registers across the call.
+    PreserveMost = 14,
+    /// Preserves all general purpose registers across the call.
+    PreserveAll = 15,
+    /// The calling convention of Swift.
+    Swift = 16,
+    /// Used for access functions of C++ thread local variables.
+    CXXFastTLS = 17,
+    /// The x86 `stdcall` convention.
+    X86StdCall = 64,
+    /// The x86 `fastcall` convention.
+    X86FastCall = 65,
+    /// The ARM APCS convention.
+    ArmAPCS = 66,
+    /// The ARM AAPCS convention.
+    ArmAAPCS = 67,
+    /// The ARM AAPCS convention with VFP registers.
+    ArmAAPCSVFP = 68,
+    /// The x86 `thiscall` convention.
+    X86ThisCall = 70,
+    /// The x86-64 System V convention.
+    X86_64SysV = 78,
+    /// The x86-64 Windows convention.
+    Win64 = 79,
+    /// The x86 `vectorcall` convention.
+    X86VectorCall = 80,
+}
+
+impl TryFrom<u32> for CallConv {
+    type Error = ();
+
+    fn try_from(val: u32) -> Result<Self, Self::Error> {
+        match val {
+            0 => Ok(CallConv::C),
+            8 => Ok(CallConv::Fast),
+            9 => Ok(CallConv::Cold),
+            10 => Ok(CallConv::GHC),
+            11 => Ok(CallConv::HiPE),
+            12 => Ok(CallConv::WebKitJS),
+            13 => Ok(CallConv::AnyReg),
+            14 => Ok(CallConv::PreserveMost),
+            15 => Ok(CallConv::PreserveAll),
+            16 => Ok(CallConv::Swift),
+            17 => Ok(CallConv::CXXFastTLS),
+            64 => Ok(CallConv::X86StdCall),
+            65 => Ok(CallConv::X86FastCall),
+            66 => Ok(CallConv::ArmAPCS),
+            67 => Ok(CallConv::ArmAAPCS),
+            68 => Ok(CallConv::ArmAAPCSVFP),
+            70 => Ok(CallConv::X86ThisCall),
+            78 => Ok(CallConv::X86_64SysV),
+            79 => Ok(CallConv::Win64),
+            80 => Ok(CallConv::X86VectorCall),
+            _ => Err(()),
+        }
+    }
+}
+
 // REVIEW: Maybe this belongs in some sort of prelude?
 /// This enum defines how to compare a `left` and `right` `IntValue`.
 #[llvm_enum(LLVMIntPredicate)]
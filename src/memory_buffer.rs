@@ -17,13 +17,22 @@ use std::slice;
 #[derive(Debug)]
 pub struct MemoryBuffer {
     pub(crate) memory_buffer: LLVMMemoryBufferRef,
+    null_terminated: bool,
 }
 
 impl MemoryBuffer {
+    /// Wraps a buffer allocated by LLVM itself, which is always null terminated.
     pub(crate) fn new(memory_buffer: LLVMMemoryBufferRef) -> Self {
+        MemoryBuffer::new_with_null_terminator(memory_buffer, true)
+    }
+
+    fn new_with_null_terminator(memory_buffer: LLVMMemoryBufferRef, null_terminated: bool) -> Self {
         assert!(!memory_buffer.is_null());
 
-        MemoryBuffer { memory_buffer }
+        MemoryBuffer {
+            memory_buffer,
+            null_terminated,
+        }
     }
 
     pub fn create_from_file(path: &Path) -> Result<Self, LLVMString> {
@@ -82,7 +91,7 @@ impl MemoryBuffer {
             )
         };
 
-        MemoryBuffer::new(memory_buffer)
+        MemoryBuffer::new_with_null_terminator(memory_buffer, false)
     }
 
     /// This will create a new `MemoryBuffer` from the given input.
@@ -113,11 +122,119 @@ impl MemoryBuffer {
         }
     }
 
+    /// Determines whether or not the byte following the contents of this `MemoryBuffer`
+    /// is guaranteed to be a null terminator. This is the case for every buffer except
+    /// those created with `create_from_memory_range`, which borrows its input as is.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    ///
+    /// let memory_buffer = MemoryBuffer::create_from_memory_range_copy(b"data", "my_buffer");
+    ///
+    /// assert!(memory_buffer.is_null_terminated());
+    /// ```
+    pub fn is_null_terminated(&self) -> bool {
+        self.null_terminated
+    }
+
+    /// Gets a byte slice of this `MemoryBuffer` which includes the null terminator
+    /// when there is one, so that it can be handed to C APIs expecting a terminator.
+    /// Otherwise this is the same as `as_slice`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    ///
+    /// let memory_buffer = MemoryBuffer::create_from_memory_range_copy(b"data", "my_buffer");
+    ///
+    /// assert_eq!(memory_buffer.as_c_slice(), b"data\0");
+    /// ```
+    pub fn as_c_slice(&self) -> &[u8] {
+        if !self.null_terminated {
+            return self.as_slice();
+        }
+
+        unsafe {
+            let start = LLVMGetBufferStart(self.memory_buffer);
+
+            slice::from_raw_parts(start as *const _, self.get_size() + 1)
+        }
+    }
+
+    /// Copies the contents of this `MemoryBuffer` into a `Vec<u8>`, which can be
+    /// kept around after the buffer itself has been dropped.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    ///
+    /// let bytes = MemoryBuffer::create_from_memory_range_copy(b"data", "my_buffer").to_vec();
+    ///
+    /// assert_eq!(bytes, b"data");
+    /// ```
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.as_slice().to_vec()
+    }
+
+    /// Determines whether this `MemoryBuffer` holds the exact same bytes as `other`.
+    /// Their names aren't compared.
+    pub fn content_eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+
+    /// Gets the offset of the first byte at which the contents of this `MemoryBuffer` and
+    /// `other` differ, which is the length of the shorter one if it is a prefix of the longer,
+    /// or `None` if their contents are equal.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    ///
+    /// let expected = MemoryBuffer::create_from_memory_range_copy(b"abcd", "expected");
+    /// let actual = MemoryBuffer::create_from_memory_range_copy(b"abxd", "actual");
+    ///
+    /// assert_eq!(expected.first_difference(&actual), Some(2));
+    /// ```
+    pub fn first_difference(&self, other: &Self) -> Option<usize> {
+        let lhs = self.as_slice();
+        let rhs = other.as_slice();
+
+        lhs.iter()
+            .zip(rhs.iter())
+            .position(|(lhs, rhs)| lhs != rhs)
+            .or_else(|| {
+                if lhs.len() == rhs.len() {
+                    None
+                } else {
+                    Some(lhs.len().min(rhs.len()))
+                }
+            })
+    }
+
     /// Gets the byte size of this `MemoryBuffer`.
     pub fn get_size(&self) -> usize {
         unsafe { LLVMGetBufferSize(self.memory_buffer) }
     }
 
+    // TODO: append_metadata_evm, to append trailing (e.g. CBOR encoded) metadata to EVM bytecode.
+    // This tree has neither an EVM target feature nor the EraVM `append_metadata_eravm` it should
+    // mirror, and the linked LLVM doesn't provide an LLVMAddMetadataEVM symbol to wrap.
+
+    // TODO: get_undefined_symbols_evm, to list the library symbols an EVM object still needs linked.
+    // As with append_metadata_evm, there is no EVM linker query in the linked LLVM to wrap yet.
+
+    // TODO: is_elf_evm, to tell ELF wrapped EVM objects which still need a link step apart from
+    // finalized bytecode. This needs an EVM ELF check symbol, which the linked LLVM doesn't have either.
+
+    // TODO: append_metadata_eravm_checked, appending metadata and rejecting the result with an
+    // `EraVMSizeError { actual, limit }` when it exceeds the bytecode size limit. Neither
+    // append_metadata_eravm nor exceeds_size_limit_eravm exist in this tree to combine yet.
+
     /// Convert this `MemoryBuffer` into an `ObjectFile`. LLVM does not currently
     /// provide any way to determine the cause of error if conversion fails.
     pub fn create_object_file(self) -> Result<ObjectFile, ()> {
@@ -5,12 +5,14 @@ use llvm_sys::analysis::{LLVMVerifierFailureAction, LLVMVerifyModule};
 use llvm_sys::bit_reader::LLVMParseBitcodeInContext;
 use llvm_sys::bit_writer::{LLVMWriteBitcodeToFile, LLVMWriteBitcodeToMemoryBuffer};
 use llvm_sys::core::{
-    LLVMAddFunction, LLVMAddGlobal, LLVMAddGlobalInAddressSpace, LLVMAddNamedMetadataOperand,
-    LLVMCloneModule, LLVMDisposeModule, LLVMDumpModule, LLVMGetFirstFunction, LLVMGetFirstGlobal,
-    LLVMGetIntrinsicDeclaration, LLVMGetLastFunction, LLVMGetLastGlobal, LLVMGetModuleContext,
-    LLVMGetNamedFunction, LLVMGetNamedGlobal, LLVMGetNamedMetadataNumOperands,
-    LLVMGetNamedMetadataOperands, LLVMGetTarget, LLVMGetTypeByName, LLVMLookupIntrinsicID,
-    LLVMPrintModuleToFile, LLVMPrintModuleToString, LLVMSetDataLayout, LLVMSetTarget,
+    LLVMAddAlias, LLVMAddFunction, LLVMAddGlobal, LLVMAddGlobalInAddressSpace,
+    LLVMAddNamedMetadataOperand, LLVMCloneModule, LLVMDisposeModule, LLVMDumpModule,
+    LLVMGetFirstFunction, LLVMGetFirstGlobal, LLVMGetIntrinsicDeclaration, LLVMGetLastFunction,
+    LLVMGetLastGlobal, LLVMGetModuleContext, LLVMGetNamedFunction, LLVMGetNamedGlobal,
+    LLVMGetNamedMetadataNumOperands, LLVMGetNamedMetadataOperands, LLVMGetTarget,
+    LLVMGetTypeByName, LLVMIntrinsicIsOverloaded, LLVMIsConstant, LLVMLookupIntrinsicID,
+    LLVMPointerType, LLVMPrintModuleToFile, LLVMPrintModuleToString, LLVMSetDataLayout,
+    LLVMSetTarget, LLVMTypeOf,
 };
 #[llvm_versions(7.0..=latest)]
 use llvm_sys::core::{LLVMAddModuleFlag, LLVMGetModuleFlag};
@@ -27,7 +29,9 @@ use llvm_sys::LLVMLinkage;
 use llvm_sys::LLVMModuleFlagBehavior;
 
 use std::cell::{Cell, Ref, RefCell};
+use std::error::Error;
 use std::ffi::CStr;
+use std::fmt::{self, Display, Formatter};
 use std::fs::File;
 use std::marker::PhantomData;
 use std::mem::{forget, MaybeUninit};
@@ -45,9 +49,10 @@ use crate::execution_engine::ExecutionEngine;
 use crate::memory_buffer::MemoryBuffer;
 use crate::support::{to_c_str, LLVMString};
 use crate::targets::{InitializationConfig, Target, TargetTriple};
-use crate::types::{AsTypeRef, BasicType, FunctionType, StructType};
-#[llvm_versions(7.0..=latest)]
+use crate::types::{AnyType, AsTypeRef, BasicType, FunctionType, StructType};
 use crate::values::BasicValue;
+#[llvm_versions(9.0..=latest)]
+use crate::values::GlobalIFunc;
 use crate::values::{AsValueRef, FunctionValue, GlobalValue, MetadataValue};
 use crate::{AddressSpace, OptimizationLevel};
 
@@ -220,6 +225,45 @@ impl<'ctx> Module<'ctx> {
         fn_value
     }
 
+    /// Gets the function named `name` from the `Module`, adding it with the given `ty` and
+    /// `linkage` as `add_function` would if there isn't one yet. Returns `Err` with both types
+    /// if a function by that name already exists with a different type. The linkage of an
+    /// existing function is left as is.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// let fn_type = context.f32_type().fn_type(&[], false);
+    /// let fn_val = module.get_or_add_function("my_function", fn_type, None).unwrap();
+    ///
+    /// assert_eq!(module.get_or_add_function("my_function", fn_type, None), Ok(fn_val));
+    /// ```
+    pub fn get_or_add_function(
+        &self,
+        name: &str,
+        ty: FunctionType<'ctx>,
+        linkage: Option<Linkage>,
+    ) -> Result<FunctionValue<'ctx>, FunctionTypeMismatch<'ctx>> {
+        let fn_value = match self.get_function(name) {
+            Some(fn_value) => fn_value,
+            None => return Ok(self.add_function(name, ty, linkage)),
+        };
+        let found = fn_value.get_type();
+
+        if found != ty {
+            return Err(FunctionTypeMismatch {
+                expected: ty,
+                found,
+            });
+        }
+
+        Ok(fn_value)
+    }
+
     /// Gets the `Context` from which this `Module` originates.
     ///
     /// # Example
@@ -329,29 +373,14 @@ impl<'ctx> Module<'ctx> {
         name: &str,
         param_types: &[T],
     ) -> Option<FunctionValue<'ctx>> {
-        let c_string = to_c_str(name);
-
-        let id = unsafe { LLVMLookupIntrinsicID(c_string.as_ptr(), name.len()) };
-
-        let mut param_types: Vec<LLVMTypeRef> = param_types
+        let param_types: Vec<LLVMTypeRef> = param_types
             .iter()
             .map(|r#type| r#type.as_type_ref())
             .collect();
 
-        let value = unsafe {
-            LLVMGetIntrinsicDeclaration(
-                self.module.get(),
-                id,
-                param_types.as_mut_ptr(),
-                param_types.len(),
-            )
-        };
+        let value = unsafe { get_intrinsic_declaration(self.module.get(), name, &param_types) };
 
-        if value.is_null() {
-            return None;
-        }
-
-        FunctionValue::new(value)
+        value.ok().and_then(FunctionValue::new)
     }
 
     /// Gets a named `StructType` from this `Module`'s `Context`.
@@ -639,6 +668,91 @@ impl<'ctx> Module<'ctx> {
         GlobalValue::new(value)
     }
 
+    /// Creates an alias named `name` in this `Module`, a `GlobalValue` of type `ty` in the given
+    /// address space which refers to `aliasee`. Returns `Err` if `aliasee` isn't a constant,
+    /// such as a global or another alias, or isn't a pointer to `ty` in that address space.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let i32_type = context.i32_type();
+    /// let global = module.add_global(i32_type, None, "my_global");
+    /// let alias = module.add_alias(i32_type, AddressSpace::Zero, global, "my_alias").unwrap();
+    ///
+    /// assert_eq!(alias.get_aliasee(), Some(global.as_pointer_value()));
+    /// ```
+    pub fn add_alias<T: BasicType<'ctx>, V: BasicValue<'ctx>>(
+        &self,
+        ty: T,
+        address_space: AddressSpace,
+        aliasee: V,
+        name: &str,
+    ) -> Result<GlobalValue<'ctx>, &'static str> {
+        let aliasee = aliasee.as_value_ref();
+        let ptr_type = unsafe { LLVMPointerType(ty.as_type_ref(), address_space as u32) };
+
+        if unsafe { LLVMIsConstant(aliasee) } == 0 {
+            return Err("The aliasee must be a constant, such as a global or another alias.");
+        }
+
+        if unsafe { LLVMTypeOf(aliasee) } != ptr_type {
+            return Err("The aliasee must be a pointer to the alias type in its address space.");
+        }
+
+        let c_string = to_c_str(name);
+        let value =
+            unsafe { LLVMAddAlias(self.module.get(), ptr_type, aliasee, c_string.as_ptr()) };
+
+        Ok(GlobalValue::new(value))
+    }
+
+    /// Creates a `GlobalIFunc` in this `Module`, an indirect function of type `ty` whose
+    /// implementation is looked up by calling `resolver` when the module is loaded.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let fn_type = context.i32_type().fn_type(&[], false);
+    /// let resolver_type = fn_type.ptr_type(AddressSpace::Zero).fn_type(&[], false);
+    /// let resolver = module.add_function("resolve_my_fn", resolver_type, None);
+    /// let ifunc = module.add_global_ifunc("my_fn", fn_type, AddressSpace::Zero, resolver);
+    ///
+    /// assert_eq!(ifunc.get_resolver(), Some(resolver));
+    /// ```
+    #[llvm_versions(9.0..=latest)]
+    pub fn add_global_ifunc(
+        &self,
+        name: &str,
+        ty: FunctionType<'ctx>,
+        address_space: AddressSpace,
+        resolver: FunctionValue<'ctx>,
+    ) -> GlobalIFunc<'ctx> {
+        use llvm_sys::core::LLVMAddGlobalIFunc;
+
+        let value = unsafe {
+            LLVMAddGlobalIFunc(
+                self.module.get(),
+                name.as_ptr() as *const ::libc::c_char,
+                name.len(),
+                ty.as_type_ref(),
+                address_space as u32,
+                resolver.as_value_ref(),
+            )
+        };
+
+        GlobalIFunc::new(value)
+    }
+
     /// Writes a `Module` to a `Path`.
     ///
     /// # Example
@@ -705,8 +819,8 @@ impl<'ctx> Module<'ctx> {
     /// let basic_block = context.append_basic_block(f, "entry");
     /// let builder = context.create_builder();
     ///
-    /// builder.position_at_end(basic_block);
-    /// builder.build_return(None);
+    /// builder.position_at_end(basic_block).unwrap();
+    /// builder.build_return(None).unwrap();
     ///
     /// let buffer = module.write_bitcode_to_memory();
     /// ```
@@ -719,14 +833,33 @@ impl<'ctx> Module<'ctx> {
     /// Ensures that the current `Module` is valid, and returns a `Result`
     /// that describes whether or not it is, returning a LLVM allocated string on error.
     ///
+    /// The verifier's diagnostic is only returned and never printed to stderr.
+    ///
     /// # Remarks
     /// See also: http://llvm.org/doxygen/Analysis_2Analysis_8cpp_source.html
     pub fn verify(&self) -> Result<(), LLVMString> {
-        let mut err_str = MaybeUninit::uninit();
+        self.verify_with_action(VerifierFailureAction::ReturnStatus)
+    }
 
-        let action = LLVMVerifierFailureAction::LLVMReturnStatusAction;
+    /// Ensures that the current `Module` is valid like `verify`, but lets the verifier
+    /// additionally print its diagnostic to stderr or abort the process on failure.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::VerifierFailureAction;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// assert!(module.verify_with_action(VerifierFailureAction::PrintMessage).is_ok());
+    /// ```
+    pub fn verify_with_action(&self, action: VerifierFailureAction) -> Result<(), LLVMString> {
+        let mut err_str = MaybeUninit::uninit();
 
-        let code = unsafe { LLVMVerifyModule(self.module.get(), action, err_str.as_mut_ptr()) };
+        let code =
+            unsafe { LLVMVerifyModule(self.module.get(), action.into(), err_str.as_mut_ptr()) };
 
         let err_str = unsafe { err_str.assume_init() };
         if code == 1 && !err_str.is_null() {
@@ -736,7 +869,7 @@ impl<'ctx> Module<'ctx> {
         Ok(())
     }
 
-    fn get_borrowed_data_layout(module: LLVMModuleRef) -> DataLayout {
+    pub(crate) fn get_borrowed_data_layout(module: LLVMModuleRef) -> DataLayout {
         #[cfg(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8"))]
         let data_layout = unsafe {
             use llvm_sys::core::LLVMGetDataLayout;
@@ -753,7 +886,8 @@ impl<'ctx> Module<'ctx> {
         DataLayout::new_borrowed(data_layout)
     }
 
-    /// Gets a smart pointer to the `DataLayout` belonging to a particular `Module`.
+    /// Gets a smart pointer to the `DataLayout` belonging to a particular `Module`. It can answer
+    /// queries such as pointer sizes and endianness according to the module's own layout.
     ///
     /// # Example
     ///
@@ -890,11 +1024,42 @@ impl<'ctx> Module<'ctx> {
         }
     }
 
+    /// Appends to the inline assembly for the `Module`, adding a newline
+    /// if the existing assembly doesn't already end with one.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// module.set_inline_assembly(".globl foo");
+    /// module.append_inline_assembly(".globl bar");
+    ///
+    /// assert!(module.print_to_string().to_string().contains("module asm \".globl bar\""));
+    /// ```
+    #[llvm_versions(7.0..=latest)]
+    pub fn append_inline_assembly(&self, asm: &str) {
+        use llvm_sys::core::LLVMAppendModuleInlineAsm;
+
+        unsafe {
+            LLVMAppendModuleInlineAsm(
+                self.module.get(),
+                asm.as_ptr() as *const ::libc::c_char,
+                asm.len(),
+            )
+        }
+    }
+
     // REVIEW: Should module take ownership of metadata?
     // REVIEW: Should we return a MetadataValue for the global since it's its own value?
     // it would be the last item in get_global_metadata I believe
     // TODOC: Appends your metadata to a global MetadataValue<Node> indexed by key
     /// Appends a `MetaDataValue` to a global list indexed by a particular key.
+    /// This is the operand list of the named metadata `key`, such as `!opencl.kernels`,
+    /// which is created if it doesn't exist yet.
     ///
     /// # Example
     ///
@@ -988,6 +1153,9 @@ impl<'ctx> Module<'ctx> {
     // SubTypes: -> Vec<MetadataValue<Node>>
     /// Obtains the global `MetaDataValue` node indexed by key, which may contain 1 string or multiple values as its `get_node_values()`
     ///
+    /// These are the operands of the named metadata `key`, such as `!llvm.module.flags`.
+    /// An empty `Vec` is returned if the named metadata doesn't exist.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -1240,6 +1408,63 @@ impl<'ctx> Module<'ctx> {
         Ok(Module::new(module))
     }
 
+    /// Creates a new `Module` from a `MemoryBuffer` with bitcode like `parse_bitcode_from_buffer`,
+    /// but also returns the warnings LLVM reported while reading it, such as debug info being
+    /// dropped when upgrading bitcode produced by a different LLVM version.
+    ///
+    /// A handler collecting the warnings is installed on the `Context` for the duration
+    /// of the call only, after which the previous diagnostic handler is restored.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Module;
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    /// use std::path::Path;
+    ///
+    /// let path = Path::new("foo/bar.bc");
+    /// let context = Context::create();
+    /// let buffer = MemoryBuffer::create_from_file(&path).unwrap();
+    /// let (module, warnings) = Module::parse_bitcode_from_buffer_with_diagnostics(&buffer, &context).unwrap();
+    ///
+    /// for warning in warnings {
+    ///     eprintln!("{}", warning);
+    /// }
+    /// ```
+    #[llvm_versions(3.9..=latest)]
+    pub fn parse_bitcode_from_buffer_with_diagnostics(
+        buffer: &MemoryBuffer,
+        context: &'ctx Context,
+    ) -> Result<(Self, Vec<String>), LLVMString> {
+        use crate::support::error_handling::collect_warnings_handler;
+        use libc::c_void;
+        use llvm_sys::core::{
+            LLVMContextGetDiagnosticContext, LLVMContextGetDiagnosticHandler,
+            LLVMContextSetDiagnosticHandler,
+        };
+
+        let mut diagnostics: Vec<String> = Vec::new();
+        let diagnostics_ptr = &mut diagnostics as *mut Vec<String> as *mut c_void;
+
+        let (previous_handler, previous_context) = unsafe {
+            (
+                LLVMContextGetDiagnosticHandler(context.context),
+                LLVMContextGetDiagnosticContext(context.context),
+            )
+        };
+
+        context.set_diagnostic_handler(collect_warnings_handler, diagnostics_ptr);
+
+        let module = Self::parse_bitcode_from_buffer(buffer, context);
+
+        unsafe {
+            LLVMContextSetDiagnosticHandler(context.context, previous_handler, previous_context)
+        }
+
+        module.map(|module| (module, diagnostics))
+    }
+
     /// A convenience function for creating a `Module` from a bitcode file for a given context.
     ///
     /// # Example
@@ -1577,6 +1802,11 @@ impl<'ctx> Module<'ctx> {
 }
 
 impl Clone for Module<'_> {
+    /// Clones this `Module` with `LLVMCloneModule`. The clone belongs to the same `Context`
+    /// but is otherwise independent, so it can be optimized or consumed by an `ExecutionEngine`
+    /// without affecting the original.
+    ///
+    /// The `Module` must be valid, as LLVM may segfault when cloning invalid ones.
     fn clone(&self) -> Self {
         // REVIEW: Is this just a LLVM 6 bug? We could conditionally compile this assertion for affected versions
         let verify = self.verify();
@@ -1603,6 +1833,83 @@ impl Drop for Module<'_> {
     }
 }
 
+/// Declares the intrinsic `name` in `module` for the given overloaded types, unless it already is,
+/// returning its declaration. This is the one place intrinsics are declared, for both
+/// `Module::get_intrinsic_function` and the `Builder`'s intrinsic calls.
+/// Returns `Err` if the intrinsic isn't known to this version of LLVM, or if it is overloaded
+/// but no types were given to overload it for.
+pub(crate) unsafe fn get_intrinsic_declaration(
+    module: LLVMModuleRef,
+    name: &str,
+    overloaded_types: &[LLVMTypeRef],
+) -> Result<LLVMValueRef, &'static str> {
+    let c_string = to_c_str(name);
+    let id = LLVMLookupIntrinsicID(c_string.as_ptr(), name.len());
+
+    if id == 0 {
+        return Err("The intrinsic is not known to this version of LLVM.");
+    }
+
+    // LLVM can't declare an overloaded intrinsic without the types it is overloaded for
+    if overloaded_types.is_empty() && LLVMIntrinsicIsOverloaded(id) != 0 {
+        return Err(
+            "The intrinsic is overloaded, so the types it is overloaded for must be given.",
+        );
+    }
+
+    let mut overloaded_types = overloaded_types.to_vec();
+    let value = LLVMGetIntrinsicDeclaration(
+        module,
+        id,
+        overloaded_types.as_mut_ptr(),
+        overloaded_types.len(),
+    );
+
+    if value.is_null() {
+        return Err("The intrinsic could not be declared.");
+    }
+
+    Ok(value)
+}
+
+/// The error returned by `Module::get_or_add_function` when a function by the
+/// requested name already exists with a different type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FunctionTypeMismatch<'ctx> {
+    /// The type the function was requested with.
+    pub expected: FunctionType<'ctx>,
+    /// The type of the function already in the `Module`.
+    pub found: FunctionType<'ctx>,
+}
+
+impl Error for FunctionTypeMismatch<'_> {}
+
+impl Display for FunctionTypeMismatch<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "FunctionTypeMismatch(expected {}, found {})",
+            self.expected.print_to_string(),
+            self.found.print_to_string()
+        )
+    }
+}
+
+#[llvm_enum(LLVMVerifierFailureAction)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// Defines what the verifier does when it finds a `Module` to be invalid.
+pub enum VerifierFailureAction {
+    /// Prints the diagnostic to stderr and aborts the process.
+    #[llvm_variant(LLVMAbortProcessAction)]
+    AbortProcess,
+    /// Prints the diagnostic to stderr and returns it.
+    #[llvm_variant(LLVMPrintMessageAction)]
+    PrintMessage,
+    /// Only returns the diagnostic.
+    #[llvm_variant(LLVMReturnStatusAction)]
+    ReturnStatus,
+}
+
 #[llvm_versions(7.0..=latest)]
 #[llvm_enum(LLVMModuleFlagBehavior)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
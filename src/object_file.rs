@@ -38,6 +38,24 @@ impl ObjectFile {
 
         SymbolIterator::new(symbol_iterator, self.object_file)
     }
+
+    /// Finds the first symbol in this `ObjectFile` with the given name.
+    pub fn get_symbol_by_name(&self, name: &str) -> Option<Symbol> {
+        let mut symbols = self.get_symbols();
+
+        loop {
+            let symbol = symbols.next()?;
+
+            if symbol.get_name().map(|n| n.to_bytes()) == Some(name.as_bytes()) {
+                // A Symbol only points at its iterator's current position, so
+                // it must take ownership of the iterator to stay valid
+                return Some(Symbol {
+                    symbol: symbol.symbol,
+                    _symbol_iterator: Some(symbols),
+                });
+            }
+        }
+    }
 }
 
 impl Drop for ObjectFile {
@@ -292,13 +310,17 @@ impl Drop for SymbolIterator {
 #[derive(Debug)]
 pub struct Symbol {
     symbol: LLVMSymbolIteratorRef,
+    _symbol_iterator: Option<SymbolIterator>,
 }
 
 impl Symbol {
     fn new(symbol: LLVMSymbolIteratorRef) -> Self {
         assert!(!symbol.is_null());
 
-        Symbol { symbol }
+        Symbol {
+            symbol,
+            _symbol_iterator: None,
+        }
     }
 
     pub fn get_name(&self) -> Option<&CStr> {
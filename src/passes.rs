@@ -54,6 +54,11 @@ use crate::OptimizationLevel;
 use std::borrow::Borrow;
 use std::marker::PhantomData;
 
+// TODO: PassBuilderOptions for the new pass manager, with set_aa_pipeline, set_call_graph_profile and
+// hooks for a target machine's pipeline extensions. LLVMRunPasses and the LLVMPassBuilderOptionsSet*
+// setters were only added to the C API in LLVM 13, so for the supported versions custom alias analysis
+// and target specific passes can only be set up through the legacy PassManager below.
+
 // REVIEW: Opt Level might be identical to targets::Option<CodeGenOptLevel>
 #[derive(Debug)]
 pub struct PassManagerBuilder {
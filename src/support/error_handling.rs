@@ -9,6 +9,9 @@ use llvm_sys::error_handling::{LLVMInstallFatalErrorHandler, LLVMResetFatalError
 use llvm_sys::prelude::LLVMDiagnosticInfoRef;
 use llvm_sys::LLVMDiagnosticSeverity;
 
+#[llvm_versions(3.9..=latest)]
+use crate::support::LLVMString;
+
 // REVIEW: Maybe it's possible to have a safe wrapper? If we can
 // wrap the provided function input ptr into a &CStr somehow
 // TODOC: Can be used like this:
@@ -44,6 +47,16 @@ impl DiagnosticInfo {
         unsafe { LLVMGetDiagInfoDescription(self.diagnostic_info) }
     }
 
+    #[llvm_versions(3.9..=latest)]
+    pub(crate) fn severity_is_warning(&self) -> bool {
+        unsafe {
+            match LLVMGetDiagInfoSeverity(self.diagnostic_info) {
+                LLVMDiagnosticSeverity::LLVMDSWarning => true,
+                _ => false,
+            }
+        }
+    }
+
     pub(crate) fn severity_is_error(&self) -> bool {
         unsafe {
             match LLVMGetDiagInfoSeverity(self.diagnostic_info) {
@@ -73,3 +86,24 @@ pub(crate) extern "C" fn get_error_str_diagnostic_handler(
         }
     }
 }
+
+// Assmuptions this handler makes:
+// * A valid *mut Vec<String> is provided as the void_ptr (via context.set_diagnostic_handler)
+//
+// Only warnings are collected, as errors are already reported by the function that failed
+#[llvm_versions(3.9..=latest)]
+pub(crate) extern "C" fn collect_warnings_handler(
+    diagnostic_info: LLVMDiagnosticInfoRef,
+    void_ptr: *mut c_void,
+) {
+    let diagnostic_info = DiagnosticInfo::new(diagnostic_info);
+
+    if !diagnostic_info.severity_is_warning() {
+        return;
+    }
+
+    let description = LLVMString::new(diagnostic_info.get_description());
+    let diagnostics = unsafe { &mut *(void_ptr as *mut Vec<String>) };
+
+    diagnostics.push(description.to_string());
+}
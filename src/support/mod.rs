@@ -31,6 +31,22 @@ impl LLVMString {
         (*self).to_string_lossy().into_owned()
     }
 
+    /// Gets this `LLVMString` as a `str`, replacing any invalid UTF-8 sequences
+    /// with `U+FFFD REPLACEMENT CHARACTER`. This only allocates when there are
+    /// invalid sequences to replace.
+    pub fn to_string_lossy(&self) -> Cow<str> {
+        (**self).to_string_lossy()
+    }
+
+    /// Converts this `LLVMString` into an owned Rust `String`, which is
+    /// handy for storing LLVM diagnostics in other error types. The original
+    /// `LLVMString` is given back if it isn't valid UTF-8.
+    pub fn into_string(self) -> Result<String, LLVMString> {
+        let string = self.to_str().map(str::to_owned);
+
+        string.map_err(|_| self)
+    }
+
     /// This method will allocate a c string through LLVM
     pub(crate) fn create_from_c_str(string: &CStr) -> LLVMString {
         let ptr = unsafe { LLVMCreateMessage(string.as_ptr() as *const _) };
@@ -179,3 +195,20 @@ fn test_to_c_str() {
         panic!();
     }
 }
+
+#[test]
+fn test_llvm_string_conversions() {
+    let llvm_string = LLVMString::create_from_str("my message\0");
+
+    assert_eq!(llvm_string.to_string_lossy(), "my message");
+    assert_eq!(llvm_string.into_string(), Ok(String::from("my message")));
+
+    let invalid_c_string = CStr::from_bytes_with_nul(b"my \xFF message\0").unwrap();
+    let llvm_string = LLVMString::create_from_c_str(invalid_c_string);
+
+    assert_eq!(llvm_string.to_string_lossy(), "my \u{FFFD} message");
+
+    let llvm_string = llvm_string.into_string().unwrap_err();
+
+    assert_eq!(llvm_string.to_bytes(), b"my \xFF message");
+}
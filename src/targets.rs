@@ -38,7 +38,7 @@ use std::mem::MaybeUninit;
 use std::path::Path;
 use std::ptr;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CodeModel {
     Default,
     JITDefault,
@@ -48,7 +48,7 @@ pub enum CodeModel {
     Large,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum RelocMode {
     Default,
     Static,
@@ -956,6 +956,8 @@ impl Target {
             OptimizationLevel::Default => LLVMCodeGenOptLevel::LLVMCodeGenLevelDefault,
             OptimizationLevel::Aggressive => LLVMCodeGenOptLevel::LLVMCodeGenLevelAggressive,
         };
+        let requested_reloc_mode = reloc_mode;
+        let requested_code_model = code_model;
         let code_model = match code_model {
             CodeModel::Default => LLVMCodeModel::LLVMCodeModelDefault,
             CodeModel::JITDefault => LLVMCodeModel::LLVMCodeModelJITDefault,
@@ -986,7 +988,11 @@ impl Target {
             return None;
         }
 
-        Some(TargetMachine::new(target_machine))
+        Some(TargetMachine::new(
+            target_machine,
+            requested_reloc_mode,
+            requested_code_model,
+        ))
     }
 
     pub fn get_first() -> Option<Self> {
@@ -1074,13 +1080,40 @@ impl Target {
 #[derive(Debug)]
 pub struct TargetMachine {
     pub(crate) target_machine: LLVMTargetMachineRef,
+    reloc_mode: RelocMode,
+    code_model: CodeModel,
 }
 
 impl TargetMachine {
-    fn new(target_machine: LLVMTargetMachineRef) -> Self {
+    fn new(
+        target_machine: LLVMTargetMachineRef,
+        reloc_mode: RelocMode,
+        code_model: CodeModel,
+    ) -> Self {
         assert!(!target_machine.is_null());
 
-        TargetMachine { target_machine }
+        TargetMachine {
+            target_machine,
+            reloc_mode,
+            code_model,
+        }
+    }
+
+    /// Gets the `RelocMode` this `TargetMachine` was created with.
+    ///
+    /// LLVM's C API has no way to query this, so the value is the one
+    /// requested in `Target::create_target_machine`. `RelocMode::Default`
+    /// is reported as is rather than resolved to the target's default.
+    pub fn get_reloc_mode(&self) -> RelocMode {
+        self.reloc_mode
+    }
+
+    /// Gets the `CodeModel` this `TargetMachine` was created with.
+    ///
+    /// As with `get_reloc_mode`, this is the requested value, so
+    /// `CodeModel::Default` is not resolved to the target's default.
+    pub fn get_code_model(&self) -> CodeModel {
+        self.code_model
     }
 
     pub fn get_target(&self) -> Target {
@@ -1238,6 +1271,11 @@ impl TargetMachine {
         Ok(MemoryBuffer::new(memory_buffer))
     }
 
+    // TODO: An emit_to_writer streaming a `TargetMachine`'s output for a `Module` into a `Write`r, so
+    // that the whole object isn't held in memory at once. This needs LLVMTargetMachineEmit to take a
+    // raw_ostream backed by a callback, which the C API doesn't offer for any of the supported versions,
+    // and copying out of write_to_memory_buffer would have the same peak memory as the caller doing it.
+
     /// Saves a `TargetMachine` to a file.
     ///
     /// # Example
@@ -1,10 +1,10 @@
-use llvm_sys::core::{LLVMConstArray, LLVMGetArrayLength};
+use llvm_sys::core::{LLVMConstArray, LLVMGetArrayLength, LLVMIsConstant};
 use llvm_sys::prelude::{LLVMTypeRef, LLVMValueRef};
 
 use crate::context::ContextRef;
 use crate::types::traits::AsTypeRef;
 use crate::types::{BasicTypeEnum, FunctionType, PointerType, Type};
-use crate::values::{ArrayValue, AsValueRef, IntValue};
+use crate::values::{ArrayValue, AsValueRef, BasicValueEnum, IntValue};
 use crate::AddressSpace;
 
 /// An `ArrayType` is the type of contiguous constants or variables.
@@ -40,6 +40,7 @@ impl<'ctx> ArrayType<'ctx> {
     }
 
     /// Gets the alignment of this `ArrayType`. Value may vary depending on the target architecture.
+    /// Returns `None` if its element type isn't sized.
     ///
     /// # Example
     ///
@@ -51,7 +52,7 @@ impl<'ctx> ArrayType<'ctx> {
     /// let i8_array_type = i8_type.array_type(3);
     /// let i8_array_type_alignment = i8_array_type.get_alignment();
     /// ```
-    pub fn get_alignment(self) -> IntValue<'ctx> {
+    pub fn get_alignment(self) -> Option<IntValue<'ctx>> {
         self.array_type.get_alignment()
     }
 
@@ -152,6 +153,57 @@ impl<'ctx> ArrayType<'ctx> {
         ArrayValue::new(value)
     }
 
+    /// Creates a constant `ArrayValue` of this `ArrayType` from `values`. Unlike `const_array`,
+    /// which builds an array of arrays, the values here are the elements of the array itself.
+    /// Returns `Err` if there isn't exactly one value per element, or if any of them isn't a
+    /// constant of the element type.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let i8_array_type = i8_type.array_type(2);
+    /// let i8_val = i8_type.const_int(1, false);
+    /// let i8_array = i8_array_type.const_array_checked(&[i8_val.into(), i8_val.into()]).unwrap();
+    ///
+    /// assert_eq!(i8_array.get_type(), i8_array_type);
+    /// assert!(i8_array_type.const_array_checked(&[i8_val.into()]).is_err());
+    /// ```
+    pub fn const_array_checked(
+        self,
+        values: &[BasicValueEnum<'ctx>],
+    ) -> Result<ArrayValue<'ctx>, &'static str> {
+        if values.len() != self.len() as usize {
+            return Err("The number of values must match the length of the array type.");
+        }
+
+        let element_type = self.get_element_type();
+
+        if values.iter().any(|value| value.get_type() != element_type) {
+            return Err("All values must be of the element type of the array type.");
+        }
+
+        if values
+            .iter()
+            .any(|value| unsafe { LLVMIsConstant(value.as_value_ref()) } == 0)
+        {
+            return Err("All values must be constants.");
+        }
+
+        let mut values: Vec<LLVMValueRef> = values.iter().map(|val| val.as_value_ref()).collect();
+        let value = unsafe {
+            LLVMConstArray(
+                element_type.as_type_ref(),
+                values.as_mut_ptr(),
+                values.len() as u32,
+            )
+        };
+
+        Ok(ArrayValue::new(value))
+    }
+
     /// Creates a constant zero value of this `ArrayType`.
     ///
     /// # Example
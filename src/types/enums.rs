@@ -222,6 +222,8 @@ impl<'ctx> AnyTypeEnum<'ctx> {
         matches!(self, AnyTypeEnum::VectorType(_))
     }
 
+    /// Determines whether this is the `VoidType`, such as the return type of a function
+    /// which doesn't return a value.
     pub fn is_void_type(self) -> bool {
         matches!(self, AnyTypeEnum::VoidType(_))
     }
@@ -184,7 +184,7 @@ impl<'ctx> FloatType<'ctx> {
     /// let f32_type_alignment = f32_type.get_alignment();
     /// ```
     pub fn get_alignment(self) -> IntValue<'ctx> {
-        self.float_type.get_alignment()
+        self.float_type.get_alignment().unwrap()
     }
 
     /// Gets a reference to the `Context` this `FloatType` was created in.
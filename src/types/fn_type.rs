@@ -6,7 +6,9 @@ use llvm_sys::prelude::LLVMTypeRef;
 use llvm_sys::LLVMTypeKind;
 
 use std::fmt;
+use std::marker::PhantomData;
 use std::mem::forget;
+use std::vec;
 
 use crate::context::ContextRef;
 use crate::types::traits::AsTypeRef;
@@ -95,6 +97,41 @@ impl<'ctx> FunctionType<'ctx> {
         raw_vec.iter().map(|val| BasicTypeEnum::new(*val)).collect()
     }
 
+    /// Iterates over the param types this `FunctionType` has.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f32_type = context.f32_type();
+    /// let i8_type = context.i8_type();
+    /// let fn_type = f32_type.fn_type(&[f32_type.into(), i8_type.into()], true);
+    /// let mut param_types = fn_type.get_param_types_iter();
+    ///
+    /// assert_eq!(param_types.next().unwrap().into_float_type(), f32_type);
+    /// assert_eq!(param_types.next().unwrap().into_int_type(), i8_type);
+    /// assert!(param_types.next().is_none());
+    /// ```
+    pub fn get_param_types_iter(self) -> ParamTypeIter<'ctx> {
+        // The C API can only copy out all of the param types at once, so the raw
+        // type refs are collected up front and wrapped lazily
+        let count = self.count_param_types();
+        let mut raw_vec: Vec<LLVMTypeRef> = Vec::with_capacity(count as usize);
+
+        unsafe {
+            LLVMGetParamTypes(self.as_type_ref(), raw_vec.as_mut_ptr());
+
+            raw_vec.set_len(count as usize);
+        }
+
+        ParamTypeIter {
+            param_types: raw_vec.into_iter(),
+            _marker: PhantomData,
+        }
+    }
+
     /// Counts the number of param types this `FunctionType` has.
     ///
     /// # Example
@@ -210,3 +247,24 @@ impl AsTypeRef for FunctionType<'_> {
         self.fn_type.ty
     }
 }
+
+/// Iterates over the param types of a `FunctionType`.
+#[derive(Debug)]
+pub struct ParamTypeIter<'ctx> {
+    param_types: vec::IntoIter<LLVMTypeRef>,
+    _marker: PhantomData<&'ctx ()>,
+}
+
+impl<'ctx> Iterator for ParamTypeIter<'ctx> {
+    type Item = BasicTypeEnum<'ctx>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.param_types.next().map(BasicTypeEnum::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.param_types.size_hint()
+    }
+}
+
+impl ExactSizeIterator for ParamTypeIter<'_> {}
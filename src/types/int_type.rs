@@ -285,7 +285,7 @@ impl<'ctx> IntType<'ctx> {
     /// let i8_type_alignment = i8_type.get_alignment();
     /// ```
     pub fn get_alignment(self) -> IntValue<'ctx> {
-        self.int_type.get_alignment()
+        self.int_type.get_alignment().unwrap()
     }
 
     /// Creates a `PointerType` with this `IntType` for its element type.
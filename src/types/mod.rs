@@ -152,10 +152,14 @@ impl<'ctx> Type<'ctx> {
         unsafe { LLVMGetUndef(self.ty) }
     }
 
-    fn get_alignment(self) -> IntValue<'ctx> {
+    fn get_alignment(self) -> Option<IntValue<'ctx>> {
+        if !self.is_sized() {
+            return None;
+        }
+
         let val = unsafe { LLVMAlignOf(self.ty) };
 
-        IntValue::new(val)
+        Some(IntValue::new(val))
     }
 
     fn get_context(self) -> ContextRef<'ctx> {
@@ -55,7 +55,7 @@ impl<'ctx> PointerType<'ctx> {
     /// let f32_ptr_type_alignment = f32_ptr_type.get_alignment();
     /// ```
     pub fn get_alignment(self) -> IntValue<'ctx> {
-        self.ptr_type.get_alignment()
+        self.ptr_type.get_alignment().unwrap()
     }
 
     /// Creates a `PointerType` with this `PointerType` for its element type.
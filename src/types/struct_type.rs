@@ -102,6 +102,7 @@ impl<'ctx> StructType<'ctx> {
 
     // TODO: impl it only for StructType<T*>?
     /// Gets the size of this `StructType`. Value may vary depending on the target architecture.
+    /// Returns `None` if the struct isn't sized, such as when it's opaque.
     ///
     /// # Example
     ///
@@ -118,6 +119,7 @@ impl<'ctx> StructType<'ctx> {
     }
 
     /// Gets the alignment of this `StructType`. Value may vary depending on the target architecture.
+    /// Returns `None` if the struct isn't sized, such as when it's opaque.
     ///
     /// # Example
     ///
@@ -129,7 +131,7 @@ impl<'ctx> StructType<'ctx> {
     /// let struct_type = context.struct_type(&[f32_type.into(), f32_type.into()], false);
     /// let struct_type_alignment = struct_type.get_alignment();
     /// ```
-    pub fn get_alignment(self) -> IntValue<'ctx> {
+    pub fn get_alignment(self) -> Option<IntValue<'ctx>> {
         self.struct_type.get_alignment()
     }
 
@@ -41,6 +41,7 @@ impl<'ctx> VectorType<'ctx> {
     }
 
     /// Gets the alignment of this `VectorType`. Value may vary depending on the target architecture.
+    /// Returns `None` if its element type isn't sized.
     ///
     /// # Example
     ///
@@ -52,7 +53,7 @@ impl<'ctx> VectorType<'ctx> {
     /// let f32_vec_type = f32_type.vec_type(7);
     /// let f32_type_alignment = f32_vec_type.get_alignment();
     /// ```
-    pub fn get_alignment(self) -> IntValue<'ctx> {
+    pub fn get_alignment(self) -> Option<IntValue<'ctx>> {
         self.vec_type.get_alignment()
     }
 
@@ -40,9 +40,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let fn_value = module.add_function("my_fn", fn_type, None);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.set_tail_call(true);
     /// ```
@@ -65,9 +65,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let fn_value = module.add_function("my_fn", fn_type, None);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.set_tail_call(true);
     ///
@@ -92,9 +92,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let fn_value = module.add_function("my_fn", fn_type, None);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// assert!(call_site_value.try_as_basic_value().is_right());
     /// ```
@@ -127,9 +127,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let enum_attribute = context.create_enum_attribute(1, 1);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.add_attribute(AttributeLoc::Return, string_attribute);
     /// call_site_value.add_attribute(AttributeLoc::Return, enum_attribute);
@@ -160,9 +160,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let enum_attribute = context.create_enum_attribute(1, 1);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// assert_eq!(call_site_value.get_called_fn_value(), fn_value);
     /// ```
@@ -193,9 +193,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let enum_attribute = context.create_enum_attribute(1, 1);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.add_attribute(AttributeLoc::Return, string_attribute);
     /// call_site_value.add_attribute(AttributeLoc::Return, enum_attribute);
@@ -227,9 +227,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let enum_attribute = context.create_enum_attribute(1, 1);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.add_attribute(AttributeLoc::Return, string_attribute);
     /// call_site_value.add_attribute(AttributeLoc::Return, enum_attribute);
@@ -269,9 +269,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let enum_attribute = context.create_enum_attribute(1, 1);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.add_attribute(AttributeLoc::Return, string_attribute);
     /// call_site_value.add_attribute(AttributeLoc::Return, enum_attribute);
@@ -317,9 +317,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let enum_attribute = context.create_enum_attribute(1, 1);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.add_attribute(AttributeLoc::Return, string_attribute);
     /// call_site_value.add_attribute(AttributeLoc::Return, enum_attribute);
@@ -352,9 +352,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let enum_attribute = context.create_enum_attribute(1, 1);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.add_attribute(AttributeLoc::Return, string_attribute);
     /// call_site_value.add_attribute(AttributeLoc::Return, enum_attribute);
@@ -394,9 +394,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let enum_attribute = context.create_enum_attribute(1, 1);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// assert_eq!(call_site_value.count_arguments(), 0);
     /// ```
@@ -422,9 +422,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let fn_value = module.add_function("my_fn", fn_type, None);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// assert_eq!(call_site_value.get_call_convention(), 0);
     /// ```
@@ -447,9 +447,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let fn_value = module.add_function("my_fn", fn_type, None);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.set_call_convention(2);
     ///
@@ -479,9 +479,9 @@ impl<'ctx> CallSiteValue<'ctx> {
     /// let fn_value = module.add_function("my_fn", fn_type, None);
     /// let entry_bb = context.append_basic_block(fn_value, "entry");
     ///
-    /// builder.position_at_end(entry_bb);
+    /// builder.position_at_end(entry_bb).unwrap();
     ///
-    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn");
+    /// let call_site_value = builder.build_call(fn_value, &[], "my_fn").unwrap();
     ///
     /// call_site_value.set_alignment_attribute(AttributeLoc::Param(0), 2);
     /// ```
@@ -1,30 +1,34 @@
 use llvm_sys::analysis::{
-    LLVMVerifierFailureAction, LLVMVerifyFunction, LLVMViewFunctionCFG, LLVMViewFunctionCFGOnly,
+    LLVMVerifierFailureAction, LLVMVerifyFunction, LLVMVerifyModule, LLVMViewFunctionCFG,
+    LLVMViewFunctionCFGOnly,
 };
 #[llvm_versions(3.9..=latest)]
 use llvm_sys::core::{
-    LLVMAddAttributeAtIndex, LLVMGetAttributeCountAtIndex, LLVMGetEnumAttributeAtIndex,
-    LLVMGetStringAttributeAtIndex, LLVMRemoveEnumAttributeAtIndex,
+    LLVMAddAttributeAtIndex, LLVMGetAttributeCountAtIndex, LLVMGetAttributesAtIndex,
+    LLVMGetEnumAttributeAtIndex, LLVMGetStringAttributeAtIndex, LLVMRemoveEnumAttributeAtIndex,
     LLVMRemoveStringAttributeAtIndex,
 };
 use llvm_sys::core::{
     LLVMCountBasicBlocks, LLVMCountParams, LLVMDeleteFunction, LLVMGetBasicBlocks,
     LLVMGetFirstBasicBlock, LLVMGetFirstParam, LLVMGetFunctionCallConv, LLVMGetGC,
-    LLVMGetIntrinsicID, LLVMGetLastBasicBlock, LLVMGetLastParam, LLVMGetLinkage,
-    LLVMGetNextFunction, LLVMGetNextParam, LLVMGetParam, LLVMGetParams, LLVMGetPreviousFunction,
-    LLVMIsAFunction, LLVMIsConstant, LLVMSetFunctionCallConv, LLVMSetGC, LLVMSetLinkage,
-    LLVMSetParamAlignment,
+    LLVMGetGlobalParent, LLVMGetIntrinsicID, LLVMGetLastBasicBlock, LLVMGetLastParam,
+    LLVMGetLinkage, LLVMGetNextFunction, LLVMGetNextParam, LLVMGetParam, LLVMGetParams,
+    LLVMGetPreviousFunction, LLVMIsAFunction, LLVMIsConstant, LLVMSetFunctionCallConv, LLVMSetGC,
+    LLVMSetLinkage, LLVMSetParamAlignment,
 };
 #[llvm_versions(3.7..=latest)]
 use llvm_sys::core::{LLVMGetPersonalityFn, LLVMSetPersonalityFn};
 #[llvm_versions(7.0..=latest)]
 use llvm_sys::debuginfo::{LLVMGetSubprogram, LLVMSetSubprogram};
+#[llvm_versions(3.9..=latest)]
+use llvm_sys::prelude::LLVMAttributeRef;
 use llvm_sys::prelude::{LLVMBasicBlockRef, LLVMValueRef};
 
+use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::fmt;
 use std::marker::PhantomData;
-use std::mem::forget;
+use std::mem::{forget, MaybeUninit};
 
 #[llvm_versions(3.9..=latest)]
 use crate::attributes::{Attribute, AttributeLoc};
@@ -32,10 +36,11 @@ use crate::basic_block::BasicBlock;
 #[llvm_versions(7.0..=latest)]
 use crate::debug_info::DISubprogram;
 use crate::module::Linkage;
-use crate::support::to_c_str;
+use crate::support::{to_c_str, LLVMString};
 use crate::types::{AnyType, FunctionType, PointerType};
 use crate::values::traits::{AnyValue, AsValueRef};
 use crate::values::{BasicValueEnum, GlobalValue, Value};
+use crate::CallConv;
 
 #[derive(PartialEq, Eq, Clone, Copy, Hash)]
 pub struct FunctionValue<'ctx> {
@@ -88,6 +93,55 @@ impl<'ctx> FunctionValue<'ctx> {
         code != 1
     }
 
+    /// Ensures that this `FunctionValue` is valid without printing anything to stderr,
+    /// returning the verifier's diagnostic on error. This makes it possible to validate
+    /// functions one at a time while the rest of the module is still being generated.
+    ///
+    /// The C API can only produce a diagnostic for a whole module, so when this function
+    /// is invalid its parent module is verified to obtain one. That diagnostic is returned
+    /// as is, so it may also describe problems in other, possibly half built, functions of
+    /// the module. A generic message is returned if the function has no parent module.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let function = module.add_function("my_fn", fn_type, None);
+    ///
+    /// context.append_basic_block(function, "entry");
+    ///
+    /// assert!(function.verify_with_message().unwrap_err().contains("does not have terminator"));
+    /// ```
+    pub fn verify_with_message(self) -> Result<(), String> {
+        let action = LLVMVerifierFailureAction::LLVMReturnStatusAction;
+
+        if unsafe { LLVMVerifyFunction(self.as_value_ref(), action) } != 1 {
+            return Ok(());
+        }
+
+        let module = unsafe { LLVMGetGlobalParent(self.as_value_ref()) };
+
+        if !module.is_null() {
+            let mut err_str = MaybeUninit::uninit();
+            let code = unsafe { LLVMVerifyModule(module, action, err_str.as_mut_ptr()) };
+            let err_str = unsafe { err_str.assume_init() };
+
+            if !err_str.is_null() {
+                let err_str = LLVMString::new(err_str).to_string();
+
+                if code == 1 && !err_str.is_empty() {
+                    return Err(err_str);
+                }
+            }
+        }
+
+        Err(format!("Function {:?} is invalid.", self.get_name()))
+    }
+
     // REVIEW: If there's a demand, could easily create a module.get_functions() -> Iterator
     pub fn get_next_function(self) -> Option<Self> {
         let function = unsafe { LLVMGetNextFunction(self.as_value_ref()) };
@@ -262,11 +316,20 @@ impl<'ctx> FunctionValue<'ctx> {
         FunctionValue::new(value)
     }
 
+    /// Sets the personality function used to unwind through this `FunctionValue` when
+    /// it handles exceptions, such as `__gxx_personality_v0`. Building a landing pad sets
+    /// its personality on the function already, so this is mainly needed for functions
+    /// which only `invoke` and don't build landing pads themselves, or for funclet based
+    /// exception handling.
     #[llvm_versions(3.7..=latest)]
     pub fn set_personality_function(self, personality_fn: FunctionValue<'ctx>) {
         unsafe { LLVMSetPersonalityFn(self.as_value_ref(), personality_fn.as_value_ref()) }
     }
 
+    // TODO: set_prefix_data/get_prefix_data and the prologue data equivalents. LLVMSetPrefixData,
+    // LLVMGetPrefixData, LLVMSetPrologueData and LLVMGetPrologueData were only added to the C API
+    // in LLVM 19, so neither can be attached to functions for any of the supported versions yet.
+
     pub fn get_intrinsic_id(self) -> u32 {
         unsafe { LLVMGetIntrinsicID(self.as_value_ref()) }
     }
@@ -279,6 +342,18 @@ impl<'ctx> FunctionValue<'ctx> {
         unsafe { LLVMSetFunctionCallConv(self.as_value_ref(), call_conventions) }
     }
 
+    /// Gets the calling convention of this `FunctionValue` as a `CallConv`, or `None`
+    /// if it isn't one of the known conventions, in which case `get_call_conventions`
+    /// still returns its numeric id.
+    pub fn get_call_convention(self) -> Option<CallConv> {
+        CallConv::try_from(self.get_call_conventions()).ok()
+    }
+
+    /// Sets the calling convention of this `FunctionValue`.
+    pub fn set_call_convention(self, call_convention: CallConv) {
+        self.set_call_conventions(call_convention as u32)
+    }
+
     pub fn get_gc(&self) -> &CStr {
         unsafe { CStr::from_ptr(LLVMGetGC(self.as_value_ref())) }
     }
@@ -468,6 +543,76 @@ impl<'ctx> FunctionValue<'ctx> {
         Some(Attribute::new(ptr))
     }
 
+    /// Gets all `Attribute`s belonging to the nth parameter of this `FunctionValue`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::{Attribute, AttributeLoc};
+    /// use inkwell::context::Context;
+    /// use inkwell::AddressSpace;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let i32_ptr_type = context.i32_type().ptr_type(AddressSpace::Zero);
+    /// let fn_type = void_type.fn_type(&[i32_ptr_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    /// let noalias_kind_id = Attribute::get_named_enum_kind_id("noalias");
+    /// let noalias_attribute = context.create_enum_attribute(noalias_kind_id, 0);
+    ///
+    /// fn_value.add_attribute(AttributeLoc::Param(0), noalias_attribute);
+    ///
+    /// assert_eq!(fn_value.get_param_attributes(0), vec![noalias_attribute]);
+    /// ```
+    #[llvm_versions(3.9..=latest)]
+    pub fn get_param_attributes(self, param_index: u32) -> Vec<Attribute> {
+        let loc = AttributeLoc::Param(param_index);
+        let count = self.count_attributes(loc);
+        let mut attribute_refs: Vec<LLVMAttributeRef> = Vec::with_capacity(count as usize);
+
+        unsafe {
+            LLVMGetAttributesAtIndex(
+                self.as_value_ref(),
+                loc.get_index(),
+                attribute_refs.as_mut_ptr(),
+            );
+
+            attribute_refs.set_len(count as usize);
+        }
+
+        attribute_refs.into_iter().map(Attribute::new).collect()
+    }
+
+    /// Iterates over the parameters of this `FunctionValue` along with the
+    /// `Attribute`s belonging to each of them.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = void_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", fn_type, None);
+    ///
+    /// for (param, attributes) in fn_value.get_params_with_attributes() {
+    ///     assert!(param.is_int_value());
+    ///     assert!(attributes.is_empty());
+    /// }
+    /// ```
+    #[llvm_versions(3.9..=latest)]
+    pub fn get_params_with_attributes(self) -> ParamAttributesIter<'ctx> {
+        ParamAttributesIter {
+            fn_value: self,
+            param_iter: self.get_param_iter(),
+            param_index: 0,
+        }
+    }
+
     pub fn set_param_alignment(self, param_index: u32, alignment: u32) {
         if let Some(param) = self.get_nth_param(param_index) {
             unsafe { LLVMSetParamAlignment(param.as_value_ref(), alignment) }
@@ -564,3 +709,26 @@ impl<'ctx> Iterator for ParamValueIter<'ctx> {
         Some(Self::Item::new(next_value))
     }
 }
+
+/// Iterates over the parameters of a `FunctionValue` and their `Attribute`s.
+#[llvm_versions(3.9..=latest)]
+#[derive(Debug)]
+pub struct ParamAttributesIter<'ctx> {
+    fn_value: FunctionValue<'ctx>,
+    param_iter: ParamValueIter<'ctx>,
+    param_index: u32,
+}
+
+#[llvm_versions(3.9..=latest)]
+impl<'ctx> Iterator for ParamAttributesIter<'ctx> {
+    type Item = (BasicValueEnum<'ctx>, Vec<Attribute>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let param = self.param_iter.next()?;
+        let attributes = self.fn_value.get_param_attributes(self.param_index);
+
+        self.param_index += 1;
+
+        Some((param, attributes))
+    }
+}
@@ -0,0 +1,64 @@
+use llvm_sys::core::{LLVMGetGlobalIFuncResolver, LLVMSetGlobalIFuncResolver};
+use llvm_sys::prelude::LLVMValueRef;
+
+use std::ffi::CStr;
+
+use crate::support::LLVMString;
+use crate::values::traits::AsValueRef;
+use crate::values::{FunctionValue, GlobalValue, PointerValue, Value};
+
+/// An indirect function, or ifunc, whose address is resolved when the module is loaded by
+/// calling its resolver. The resolver takes no arguments and returns a pointer to the
+/// implementation, which makes ifuncs useful for dispatching on CPU features.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct GlobalIFunc<'ctx> {
+    global_ifunc: Value<'ctx>,
+}
+
+impl<'ctx> GlobalIFunc<'ctx> {
+    pub(crate) fn new(value: LLVMValueRef) -> Self {
+        assert!(!value.is_null());
+
+        GlobalIFunc {
+            global_ifunc: Value::new(value),
+        }
+    }
+
+    /// Gets the name of a `GlobalIFunc`.
+    pub fn get_name(&self) -> &CStr {
+        self.global_ifunc.get_name()
+    }
+
+    /// Gets the resolver called to pick this `GlobalIFunc`'s implementation, if it has one.
+    pub fn get_resolver(self) -> Option<FunctionValue<'ctx>> {
+        let resolver = unsafe { LLVMGetGlobalIFuncResolver(self.as_value_ref()) };
+
+        FunctionValue::new(resolver)
+    }
+
+    /// Sets the resolver called to pick this `GlobalIFunc`'s implementation.
+    pub fn set_resolver(self, resolver: FunctionValue<'ctx>) {
+        unsafe { LLVMSetGlobalIFuncResolver(self.as_value_ref(), resolver.as_value_ref()) }
+    }
+
+    /// Gets this `GlobalIFunc` as a `GlobalValue`, such as to change its linkage or visibility.
+    pub fn as_global_value(self) -> GlobalValue<'ctx> {
+        GlobalValue::new(self.as_value_ref())
+    }
+
+    /// Gets this `GlobalIFunc` as a `PointerValue` to the function, which can be called
+    /// like any other function pointer.
+    pub fn as_pointer_value(self) -> PointerValue<'ctx> {
+        PointerValue::new(self.as_value_ref())
+    }
+
+    pub fn print_to_string(self) -> LLVMString {
+        self.global_ifunc.print_to_string()
+    }
+}
+
+impl AsValueRef for GlobalIFunc<'_> {
+    fn as_value_ref(&self) -> LLVMValueRef {
+        self.global_ifunc.value
+    }
+}
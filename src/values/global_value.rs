@@ -1,5 +1,7 @@
-#[llvm_versions(8.0..=latest)]
-use llvm_sys::core::LLVMGlobalSetMetadata;
+#[llvm_versions(7.0..=latest)]
+use llvm_sys::core::{
+    LLVMAliasGetAliasee, LLVMAliasSetAliasee, LLVMIsAGlobalAlias, LLVMIsConstant,
+};
 #[llvm_versions(3.6..8.0)]
 use llvm_sys::core::{
     LLVMDeleteGlobal, LLVMGetAlignment, LLVMGetDLLStorageClass, LLVMGetInitializer, LLVMGetLinkage,
@@ -18,6 +20,13 @@ use llvm_sys::core::{
     LLVMSetGlobalConstant, LLVMSetInitializer, LLVMSetLinkage, LLVMSetSection, LLVMSetThreadLocal,
     LLVMSetThreadLocalMode, LLVMSetVisibility,
 };
+#[llvm_versions(8.0..=latest)]
+use llvm_sys::core::{
+    LLVMDisposeValueMetadataEntries, LLVMGetTypeContext, LLVMGlobalCopyAllMetadata,
+    LLVMGlobalSetMetadata, LLVMMetadataAsValue, LLVMValueMetadataEntriesGetKind,
+    LLVMValueMetadataEntriesGetMetadata,
+};
+use llvm_sys::core::{LLVMGetElementType, LLVMTypeOf};
 #[llvm_versions(7.0..=latest)]
 use llvm_sys::core::{LLVMGetUnnamedAddress, LLVMSetUnnamedAddress};
 #[llvm_versions(3.6..=6.0)]
@@ -101,8 +110,32 @@ impl<'ctx> GlobalValue<'ctx> {
     }
 
     // SubType: This input type should be tied to the BasicType
-    pub fn set_initializer(self, value: &dyn BasicValue<'ctx>) {
+    /// Sets the initializer of this `GlobalValue`. Returns `Err` if the type of the
+    /// initializer doesn't match the value type of the global.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i8_type = context.i8_type();
+    /// let global = module.add_global(i8_type, None, "my_global");
+    ///
+    /// assert!(global.set_initializer(&i8_type.const_zero()).is_ok());
+    /// assert!(global.set_initializer(&context.i16_type().const_zero()).is_err());
+    /// ```
+    pub fn set_initializer(self, value: &dyn BasicValue<'ctx>) -> Result<(), &'static str> {
+        let value_type = unsafe { LLVMGetElementType(self.global_value.get_type()) };
+
+        if value_type != unsafe { LLVMTypeOf(value.as_value_ref()) } {
+            return Err("The initializer's type does not match the global's value type.");
+        }
+
         unsafe { LLVMSetInitializer(self.as_value_ref(), value.as_value_ref()) }
+
+        Ok(())
     }
 
     pub fn is_thread_local(self) -> bool {
@@ -192,10 +225,15 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { LLVMSetGlobalConstant(self.as_value_ref(), is_constant as i32) }
     }
 
+    /// Determines whether this global is marked `externally_initialized`.
     pub fn is_externally_initialized(self) -> bool {
         unsafe { LLVMIsExternallyInitialized(self.as_value_ref()) == 1 }
     }
 
+    /// Marks this global as `externally_initialized`, meaning its memory may be written to
+    /// before any code of the module runs, such as by a host mapping it in. The optimizer will
+    /// then not assume that the global still holds its initializer, although it may be
+    /// given one.
     pub fn set_externally_initialized(self, externally_initialized: bool) {
         unsafe { LLVMSetExternallyInitialized(self.as_value_ref(), externally_initialized as i32) }
     }
@@ -220,14 +258,58 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { LLVMSetSection(self.as_value_ref(), c_string.as_ptr()) }
     }
 
-    pub unsafe fn delete(self) {
-        LLVMDeleteGlobal(self.as_value_ref())
+    /// Removes this `GlobalValue` from its module and deletes it. This is unsafe because you could
+    /// easily have other references to the same `GlobalValue`. It returns `Err` when the global is
+    /// still in use, as deleting it would leave its users dangling.
+    pub unsafe fn delete(self) -> Result<(), &'static str> {
+        if self.global_value.get_first_use().is_some() {
+            return Err("The global cannot be deleted while it still has uses.");
+        }
+
+        LLVMDeleteGlobal(self.as_value_ref());
+
+        Ok(())
     }
 
     pub fn as_pointer_value(self) -> PointerValue<'ctx> {
         PointerValue::new(self.as_value_ref())
     }
 
+    /// Gets the value this `GlobalValue` refers to if it is an alias, or `None` otherwise.
+    #[llvm_versions(7.0..=latest)]
+    pub fn get_aliasee(self) -> Option<PointerValue<'ctx>> {
+        if unsafe { LLVMIsAGlobalAlias(self.as_value_ref()) }.is_null() {
+            return None;
+        }
+
+        let aliasee = unsafe { LLVMAliasGetAliasee(self.as_value_ref()) };
+
+        Some(PointerValue::new(aliasee))
+    }
+
+    /// Makes this `GlobalValue` refer to `aliasee` instead. Returns `Err` if this `GlobalValue`
+    /// isn't an alias, or if `aliasee` isn't a constant of the same type.
+    #[llvm_versions(7.0..=latest)]
+    pub fn set_aliasee<V: BasicValue<'ctx>>(self, aliasee: V) -> Result<(), &'static str> {
+        if unsafe { LLVMIsAGlobalAlias(self.as_value_ref()) }.is_null() {
+            return Err("Only aliases can have their aliasee set.");
+        }
+
+        let aliasee = aliasee.as_value_ref();
+
+        if unsafe { LLVMIsConstant(aliasee) } == 0 {
+            return Err("The aliasee must be a constant, such as a global or another alias.");
+        }
+
+        if unsafe { LLVMTypeOf(aliasee) != LLVMTypeOf(self.as_value_ref()) } {
+            return Err("The aliasee must be of the same type as the alias.");
+        }
+
+        unsafe { LLVMAliasSetAliasee(self.as_value_ref(), aliasee) }
+
+        Ok(())
+    }
+
     pub fn get_alignment(self) -> u32 {
         unsafe { LLVMGetAlignment(self.as_value_ref()) }
     }
@@ -242,6 +324,33 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { LLVMGlobalSetMetadata(self.as_value_ref(), kind_id, metadata.as_metadata_ref()) }
     }
 
+    /// Gets all metadata attached to the GlobalValue, along with their kind ids
+    #[llvm_versions(8.0..=latest)]
+    pub fn get_all_metadata(self) -> Vec<(u32, MetadataValue<'ctx>)> {
+        let mut count = 0;
+
+        unsafe {
+            let context = LLVMGetTypeContext(LLVMTypeOf(self.as_value_ref()));
+            let entries = LLVMGlobalCopyAllMetadata(self.as_value_ref(), &mut count);
+
+            let metadata = (0..count as u32)
+                .map(|index| {
+                    let kind_id = LLVMValueMetadataEntriesGetKind(entries, index);
+                    let metadata_ref = LLVMValueMetadataEntriesGetMetadata(entries, index);
+                    let metadata = MetadataValue::new(LLVMMetadataAsValue(context, metadata_ref));
+
+                    (kind_id, metadata)
+                })
+                .collect();
+
+            if !entries.is_null() {
+                LLVMDisposeValueMetadataEntries(entries);
+            }
+
+            metadata
+        }
+    }
+
     /// Gets a `Comdat` assigned to this `GlobalValue`, if any.
     #[llvm_versions(7.0..=latest)]
     pub fn get_comdat(self) -> Option<Comdat> {
@@ -264,6 +373,7 @@ impl<'ctx> GlobalValue<'ctx> {
         unsafe { LLVMSetComdat(self.as_value_ref(), comdat.0) }
     }
 
+    /// Gets whether the address of this `GlobalValue` is significant, see `UnnamedAddress`.
     #[llvm_versions(7.0..=latest)]
     pub fn get_unnamed_address(self) -> UnnamedAddress {
         use llvm_sys::core::LLVMGetUnnamedAddress;
@@ -273,6 +383,9 @@ impl<'ctx> GlobalValue<'ctx> {
         UnnamedAddress::new(unnamed_address)
     }
 
+    /// Sets whether the address of this `GlobalValue` is significant. Constants with an insignificant
+    /// address, such as those produced by `Builder::build_global_string_ptr`, may be merged with
+    /// identical ones.
     #[llvm_versions(7.0..=latest)]
     pub fn set_unnamed_address(self, address: UnnamedAddress) {
         use llvm_sys::core::LLVMSetUnnamedAddress;
@@ -5,12 +5,12 @@ use either::{
 #[llvm_versions(3.9..=latest)]
 use llvm_sys::core::LLVMInstructionRemoveFromParent;
 use llvm_sys::core::{
-    LLVMGetAlignment, LLVMGetFCmpPredicate, LLVMGetICmpPredicate, LLVMGetInstructionOpcode,
-    LLVMGetInstructionParent, LLVMGetMetadata, LLVMGetNextInstruction, LLVMGetNumOperands,
-    LLVMGetOperand, LLVMGetOperandUse, LLVMGetPreviousInstruction, LLVMGetVolatile,
-    LLVMHasMetadata, LLVMInstructionClone, LLVMInstructionEraseFromParent, LLVMIsAAllocaInst,
-    LLVMIsABasicBlock, LLVMIsALoadInst, LLVMIsAStoreInst, LLVMIsTailCall, LLVMSetAlignment,
-    LLVMSetMetadata, LLVMSetOperand, LLVMSetVolatile, LLVMValueAsBasicBlock,
+    LLVMAddCase, LLVMGetAlignment, LLVMGetFCmpPredicate, LLVMGetICmpPredicate,
+    LLVMGetInstructionOpcode, LLVMGetInstructionParent, LLVMGetMetadata, LLVMGetNextInstruction,
+    LLVMGetNumOperands, LLVMGetOperand, LLVMGetOperandUse, LLVMGetPreviousInstruction,
+    LLVMGetVolatile, LLVMHasMetadata, LLVMInstructionClone, LLVMInstructionEraseFromParent,
+    LLVMIsAAllocaInst, LLVMIsABasicBlock, LLVMIsALoadInst, LLVMIsAStoreInst, LLVMIsTailCall,
+    LLVMSetAlignment, LLVMSetMetadata, LLVMSetOperand, LLVMSetVolatile, LLVMValueAsBasicBlock,
 };
 #[llvm_versions(3.8..=latest)]
 use llvm_sys::core::{LLVMGetOrdering, LLVMSetOrdering};
@@ -21,7 +21,7 @@ use llvm_sys::LLVMOpcode;
 
 use crate::basic_block::BasicBlock;
 use crate::values::traits::AsValueRef;
-use crate::values::{BasicValue, BasicValueEnum, BasicValueUse, MetadataValue, Value};
+use crate::values::{BasicValue, BasicValueEnum, BasicValueUse, IntValue, MetadataValue, Value};
 use crate::{AtomicOrdering, FloatPredicate, IntPredicate};
 
 // REVIEW: Split up into structs for SubTypes on InstructionValues?
@@ -343,13 +343,13 @@ impl<'ctx> InstructionValue<'ctx> {
     /// let function = module.add_function("take_f32_ptr", fn_type, None);
     /// let basic_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(basic_block);
+    /// builder.position_at_end(basic_block).unwrap();
     ///
     /// let arg1 = function.get_first_param().unwrap().into_pointer_value();
     /// let f32_val = f32_type.const_float(::std::f64::consts::PI);
-    /// let store_instruction = builder.build_store(arg1, f32_val);
-    /// let free_instruction = builder.build_free(arg1);
-    /// let return_instruction = builder.build_return(None);
+    /// let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    /// let free_instruction = builder.build_free(arg1).unwrap();
+    /// let return_instruction = builder.build_return(None).unwrap();
     ///
     /// assert_eq!(store_instruction.get_num_operands(), 2);
     /// assert_eq!(free_instruction.get_num_operands(), 2);
@@ -403,13 +403,13 @@ impl<'ctx> InstructionValue<'ctx> {
     /// let function = module.add_function("take_f32_ptr", fn_type, None);
     /// let basic_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(basic_block);
+    /// builder.position_at_end(basic_block).unwrap();
     ///
     /// let arg1 = function.get_first_param().unwrap().into_pointer_value();
     /// let f32_val = f32_type.const_float(::std::f64::consts::PI);
-    /// let store_instruction = builder.build_store(arg1, f32_val);
-    /// let free_instruction = builder.build_free(arg1);
-    /// let return_instruction = builder.build_return(None);
+    /// let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    /// let free_instruction = builder.build_free(arg1).unwrap();
+    /// let return_instruction = builder.build_return(None).unwrap();
     ///
     /// assert!(store_instruction.get_operand(0).is_some());
     /// assert!(store_instruction.get_operand(1).is_some());
@@ -488,13 +488,13 @@ impl<'ctx> InstructionValue<'ctx> {
     /// let function = module.add_function("take_f32_ptr", fn_type, None);
     /// let basic_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(basic_block);
+    /// builder.position_at_end(basic_block).unwrap();
     ///
     /// let arg1 = function.get_first_param().unwrap().into_pointer_value();
     /// let f32_val = f32_type.const_float(::std::f64::consts::PI);
-    /// let store_instruction = builder.build_store(arg1, f32_val);
-    /// let free_instruction = builder.build_free(arg1);
-    /// let return_instruction = builder.build_return(None);
+    /// let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    /// let free_instruction = builder.build_free(arg1).unwrap();
+    /// let return_instruction = builder.build_return(None).unwrap();
     ///
     /// // This will produce invalid IR:
     /// free_instruction.set_operand(0, f32_val);
@@ -531,13 +531,13 @@ impl<'ctx> InstructionValue<'ctx> {
     /// let function = module.add_function("take_f32_ptr", fn_type, None);
     /// let basic_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(basic_block);
+    /// builder.position_at_end(basic_block).unwrap();
     ///
     /// let arg1 = function.get_first_param().unwrap().into_pointer_value();
     /// let f32_val = f32_type.const_float(::std::f64::consts::PI);
-    /// let store_instruction = builder.build_store(arg1, f32_val);
-    /// let free_instruction = builder.build_free(arg1);
-    /// let return_instruction = builder.build_return(None);
+    /// let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    /// let free_instruction = builder.build_free(arg1).unwrap();
+    /// let return_instruction = builder.build_return(None).unwrap();
     ///
     /// assert_eq!(store_instruction.get_operand_use(1), arg1.get_first_use());
     /// ```
@@ -577,13 +577,13 @@ impl<'ctx> InstructionValue<'ctx> {
     /// let function = module.add_function("take_f32_ptr", fn_type, None);
     /// let basic_block = context.append_basic_block(function, "entry");
     ///
-    /// builder.position_at_end(basic_block);
+    /// builder.position_at_end(basic_block).unwrap();
     ///
     /// let arg1 = function.get_first_param().unwrap().into_pointer_value();
     /// let f32_val = f32_type.const_float(::std::f64::consts::PI);
-    /// let store_instruction = builder.build_store(arg1, f32_val);
-    /// let free_instruction = builder.build_free(arg1);
-    /// let return_instruction = builder.build_return(None);
+    /// let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    /// let free_instruction = builder.build_free(arg1).unwrap();
+    /// let return_instruction = builder.build_return(None).unwrap();
     ///
     /// assert!(arg1.get_first_use().is_some());
     /// ```
@@ -627,6 +627,48 @@ impl<'ctx> InstructionValue<'ctx> {
         }
     }
 
+    // SubTypes: Only apply to switch instructions
+    /// Adds a case to a `Switch` `InstructionValue`, branching to `dest` when the
+    /// switch condition equals `on_val`.
+    pub fn add_case(
+        self,
+        on_val: IntValue<'ctx>,
+        dest: BasicBlock<'ctx>,
+    ) -> Result<(), &'static str> {
+        if self.get_opcode() != InstructionOpcode::Switch {
+            return Err("Value is not a switch.");
+        }
+
+        unsafe { LLVMAddCase(self.as_value_ref(), on_val.as_value_ref(), dest.basic_block) }
+
+        Ok(())
+    }
+
+    // SubTypes: Only apply to switch instructions
+    /// Gets the cases of a `Switch` `InstructionValue`, not including its default destination.
+    pub fn get_switch_cases(self) -> Result<Vec<(IntValue<'ctx>, BasicBlock<'ctx>)>, &'static str> {
+        if self.get_opcode() != InstructionOpcode::Switch {
+            return Err("Value is not a switch.");
+        }
+
+        // Operands are laid out as the condition and default destination, followed by
+        // a pair of case value and destination for each case
+        let num_cases = (self.get_num_operands() - 2) / 2;
+
+        let cases = (0..num_cases)
+            .map(|case| unsafe {
+                let value = LLVMGetOperand(self.as_value_ref(), 2 + case * 2);
+                let dest = LLVMGetOperand(self.as_value_ref(), 3 + case * 2);
+                let dest = BasicBlock::new(LLVMValueAsBasicBlock(dest))
+                    .expect("Switch case destination should always be a basic block");
+
+                (IntValue::new(value), dest)
+            })
+            .collect();
+
+        Ok(cases)
+    }
+
     /// Determines whether or not this `Instruction` has any associated metadata.
     pub fn has_metadata(self) -> bool {
         unsafe { LLVMHasMetadata(self.instruction_value.value) == 1 }
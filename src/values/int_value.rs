@@ -402,6 +402,70 @@ impl<'ctx> IntValue<'ctx> {
         unsafe { Some(LLVMConstIntGetSExtValue(self.as_value_ref())) }
     }
 
+    /// Obtains a constant `IntValue`'s zero extended value. Unlike `get_zero_extended_constant`,
+    /// this handles integers up to 128 bits wide.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i128_type = context.i128_type();
+    /// let i128_val = i128_type.const_int_arbitrary_precision(&[7, 1]);
+    ///
+    /// assert_eq!(i128_val.get_zero_extended_constant_u128(), Some((1 << 64) + 7));
+    /// ```
+    pub fn get_zero_extended_constant_u128(self) -> Option<u128> {
+        let int_type = self.get_type();
+        let bit_width = int_type.get_bit_width();
+
+        if bit_width <= 64 {
+            return self.get_zero_extended_constant().map(u128::from);
+        }
+        if bit_width > 128 || !self.is_constant_int() {
+            return None;
+        }
+
+        // Constant folding turns the truncated halves back into ConstantInts
+        let context = int_type.get_context();
+        let i64_type = context.i64_type();
+        let low = self.const_truncate(i64_type).get_zero_extended_constant()?;
+        let high = self
+            .const_rshr(int_type.const_int(64, false))
+            .const_truncate(i64_type)
+            .get_zero_extended_constant()?;
+
+        Some(((high as u128) << 64) | low as u128)
+    }
+
+    /// Obtains a constant `IntValue`'s sign extended value. Unlike `get_sign_extended_constant`,
+    /// this handles integers up to 128 bits wide.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i128_type = context.i128_type();
+    /// let i128_all_ones = i128_type.const_all_ones();
+    ///
+    /// assert_eq!(i128_all_ones.get_sign_extended_constant_i128(), Some(-1));
+    /// ```
+    pub fn get_sign_extended_constant_i128(self) -> Option<i128> {
+        let bit_width = self.get_type().get_bit_width();
+
+        if bit_width <= 64 {
+            return self.get_sign_extended_constant().map(i128::from);
+        }
+
+        let shift = 128 - bit_width;
+        let constant = self.get_zero_extended_constant_u128()?;
+
+        Some(((constant << shift) as i128) >> shift)
+    }
+
     pub fn replace_all_uses_with(self, other: IntValue<'ctx>) {
         self.int_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -10,6 +10,8 @@ mod enums;
 mod float_value;
 mod fn_value;
 mod generic_value;
+#[llvm_versions(9.0..=latest)]
+mod global_ifunc;
 mod global_value;
 mod instruction_value;
 mod int_value;
@@ -30,6 +32,8 @@ pub use crate::values::enums::{
 pub use crate::values::float_value::FloatValue;
 pub use crate::values::fn_value::FunctionValue;
 pub use crate::values::generic_value::GenericValue;
+#[llvm_versions(9.0..=latest)]
+pub use crate::values::global_ifunc::GlobalIFunc;
 pub use crate::values::global_value::GlobalValue;
 #[llvm_versions(7.0..=latest)]
 pub use crate::values::global_value::UnnamedAddress;
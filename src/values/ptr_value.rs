@@ -1,12 +1,12 @@
 use llvm_sys::core::{
     LLVMConstAddrSpaceCast, LLVMConstGEP, LLVMConstInBoundsGEP, LLVMConstPointerCast,
-    LLVMConstPtrToInt,
+    LLVMConstPtrToInt, LLVMGetElementType,
 };
 use llvm_sys::prelude::LLVMValueRef;
 
 use std::ffi::CStr;
 
-use crate::types::{AsTypeRef, IntType, PointerType};
+use crate::types::{AsTypeRef, BasicType, BasicTypeEnum, IntType, PointerType};
 use crate::values::{AsValueRef, InstructionValue, IntValue, Value};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
@@ -66,7 +66,33 @@ impl<'ctx> PointerValue<'ctx> {
 
     // REVIEW: Should this be on array value too?
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
-    pub unsafe fn const_gep(self, ordered_indexes: &[IntValue<'ctx>]) -> PointerValue<'ctx> {
+    ///
+    /// The element type `ty` is what is being indexed into, which pointers will no longer carry once they are opaque.
+    /// Returns `Err` if this pointer or any of the indexes is not a constant, or if `ty` isn't the type this pointer points to.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let array_type = i32_type.array_type(4);
+    /// let global = module.add_global(array_type, Some(AddressSpace::Zero), "my_array");
+    /// let indexes = [i32_type.const_zero(), i32_type.const_int(2, false)];
+    /// let third_element_ptr = unsafe { global.as_pointer_value().const_gep(array_type, &indexes).unwrap() };
+    ///
+    /// assert!(third_element_ptr.is_const());
+    /// ```
+    pub unsafe fn const_gep<T: BasicType<'ctx>>(
+        self,
+        ty: T,
+        ordered_indexes: &[IntValue<'ctx>],
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_const_gep(ty.as_basic_type_enum(), ordered_indexes)?;
+
         let mut index_values: Vec<LLVMValueRef> = ordered_indexes
             .iter()
             .map(|val| val.as_value_ref())
@@ -79,14 +105,20 @@ impl<'ctx> PointerValue<'ctx> {
             )
         };
 
-        PointerValue::new(value)
+        Ok(PointerValue::new(value))
     }
 
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
-    pub unsafe fn const_in_bounds_gep(
+    ///
+    /// The element type `ty` is what is being indexed into, which pointers will no longer carry once they are opaque.
+    /// Returns `Err` if this pointer or any of the indexes is not a constant, or if `ty` isn't the type this pointer points to.
+    pub unsafe fn const_in_bounds_gep<T: BasicType<'ctx>>(
         self,
+        ty: T,
         ordered_indexes: &[IntValue<'ctx>],
-    ) -> PointerValue<'ctx> {
+    ) -> Result<PointerValue<'ctx>, &'static str> {
+        self.check_const_gep(ty.as_basic_type_enum(), ordered_indexes)?;
+
         let mut index_values: Vec<LLVMValueRef> = ordered_indexes
             .iter()
             .map(|val| val.as_value_ref())
@@ -99,7 +131,26 @@ impl<'ctx> PointerValue<'ctx> {
             )
         };
 
-        PointerValue::new(value)
+        Ok(PointerValue::new(value))
+    }
+
+    /// Used by const_gep and const_in_bounds_gep
+    fn check_const_gep(
+        self,
+        ty: BasicTypeEnum<'ctx>,
+        ordered_indexes: &[IntValue<'ctx>],
+    ) -> Result<(), &'static str> {
+        if !self.is_const() || !ordered_indexes.iter().all(|index| index.is_const()) {
+            return Err("Constant GEPs require a constant pointer and constant indexes.");
+        }
+
+        let element_type = unsafe { LLVMGetElementType(self.get_type().as_type_ref()) };
+
+        if element_type != ty.as_type_ref() {
+            return Err("The element type doesn't match the type the pointer points to.");
+        }
+
+        Ok(())
     }
 
     pub fn const_to_int(self, int_type: IntType<'ctx>) -> IntValue<'ctx> {
@@ -21,6 +21,7 @@ mod test_context;
 mod test_debug_info;
 mod test_execution_engine;
 mod test_instruction_values;
+mod test_memory_buffer;
 mod test_module;
 mod test_object_file;
 mod test_passes;
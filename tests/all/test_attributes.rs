@@ -1,7 +1,8 @@
 extern crate inkwell;
 
-use self::inkwell::attributes::{Attribute, AttributeLoc};
+use self::inkwell::attributes::{Attribute, AttributeLoc, EnumAttributeKind};
 use self::inkwell::context::Context;
+use self::inkwell::AddressSpace;
 
 #[test]
 fn test_enum_attribute_kinds() {
@@ -28,6 +29,64 @@ fn test_enum_attribute_kinds() {
     // assert_eq!(Attribute::get_named_enum_kind_id("shadowcallstack"), 45);
 }
 
+#[test]
+fn test_typed_enum_attributes() {
+    let context = Context::create();
+    let dereferenceable_attribute = context.create_dereferenceable_attribute(8);
+
+    assert!(dereferenceable_attribute.is_enum());
+    assert_eq!(
+        dereferenceable_attribute.get_enum_kind_id(),
+        Attribute::get_named_enum_kind_id("dereferenceable")
+    );
+    assert_eq!(dereferenceable_attribute.get_enum_value(), 8);
+
+    let align_attribute = context.create_align_attribute(16).unwrap();
+
+    assert_eq!(
+        align_attribute.get_enum_kind_id(),
+        Attribute::get_named_enum_kind_id("align")
+    );
+    assert_eq!(align_attribute.get_enum_value(), 16);
+    assert!(context.create_align_attribute(0).is_err());
+    assert!(context.create_align_attribute(12).is_err());
+
+    let kinds = [
+        EnumAttributeKind::NoAlias,
+        EnumAttributeKind::NonNull,
+        EnumAttributeKind::NoCapture,
+        EnumAttributeKind::NoUnwind,
+        EnumAttributeKind::ReadOnly,
+    ];
+
+    for kind in kinds.iter() {
+        let attribute = context.create_named_enum_attribute(*kind);
+
+        assert_ne!(kind.get_kind_id(), 0);
+        assert_eq!(attribute.get_enum_kind_id(), kind.get_kind_id());
+        assert_eq!(attribute.get_enum_value(), 0);
+    }
+}
+
+#[test]
+fn test_checked_enum_attributes() {
+    let context = Context::create();
+    let last_kind_id = Attribute::get_last_enum_kind_id();
+
+    assert!(context.create_enum_attribute_checked(0, 0).is_err());
+    assert!(context
+        .create_enum_attribute_checked(last_kind_id + 1, 0)
+        .is_err());
+    assert!(context
+        .create_enum_attribute_checked(last_kind_id, 0)
+        .is_ok());
+
+    let kind_id = Attribute::get_named_enum_kind_id("noalias");
+    let noalias_attribute = context.create_enum_attribute_checked(kind_id, 0).unwrap();
+
+    assert_eq!(noalias_attribute.get_enum_kind_id(), kind_id);
+}
+
 #[test]
 fn test_string_attributes() {
     let context = Context::create();
@@ -57,8 +116,8 @@ fn test_attributes_on_function_values() {
     let alignstack_attribute = Attribute::get_named_enum_kind_id("alignstack");
     let enum_attribute = context.create_enum_attribute(alignstack_attribute, 1);
 
-    builder.position_at_end(entry_bb);
-    builder.build_return(None);
+    builder.position_at_end(entry_bb).unwrap();
+    builder.build_return(None).unwrap();
 
     assert_eq!(fn_value.count_attributes(AttributeLoc::Return), 0);
     assert_eq!(fn_value.count_attributes(AttributeLoc::Param(0)), 0);
@@ -112,12 +171,13 @@ fn test_attributes_on_call_site_values() {
     let align_attribute = Attribute::get_named_enum_kind_id("align");
     let enum_attribute = context.create_enum_attribute(alignstack_attribute, 1);
 
-    builder.position_at_end(entry_bb);
+    builder.position_at_end(entry_bb).unwrap();
 
-    let call_site_value =
-        builder.build_call(fn_value, &[i32_type.const_int(1, false).into()], "my_fn");
+    let call_site_value = builder
+        .build_call(fn_value, &[i32_type.const_int(1, false).into()], "my_fn")
+        .unwrap();
 
-    builder.build_return(None);
+    builder.build_return(None).unwrap();
 
     assert_eq!(call_site_value.count_arguments(), 1);
     assert_eq!(call_site_value.count_attributes(AttributeLoc::Return), 0);
@@ -163,3 +223,33 @@ fn test_attributes_on_call_site_values() {
         .get_enum_attribute(AttributeLoc::Return, align_attribute)
         .is_some());
 }
+
+#[test]
+fn test_param_attributes() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Zero);
+    let fn_type = void_type.fn_type(&[i32_ptr_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let byval_kind_id = Attribute::get_named_enum_kind_id("byval");
+    let byval_attribute = context.create_enum_attribute(byval_kind_id, 0);
+
+    assert!(fn_value.get_param_attributes(0).is_empty());
+
+    // declare void @my_fn(i32* byval, i32)
+    fn_value.add_attribute(AttributeLoc::Param(0), byval_attribute);
+
+    assert_eq!(fn_value.get_param_attributes(0), vec![byval_attribute]);
+    assert!(fn_value.get_param_attributes(1).is_empty());
+
+    let params: Vec<_> = fn_value.get_params_with_attributes().collect();
+
+    assert_eq!(params.len(), 2);
+    assert_eq!(params[0].0, fn_value.get_nth_param(0).unwrap());
+    assert_eq!(params[0].1.len(), 1);
+    assert_eq!(params[0].1[0].get_enum_kind_id(), byval_kind_id);
+    assert_eq!(params[1].0, fn_value.get_nth_param(1).unwrap());
+    assert!(params[1].1.is_empty());
+}
@@ -129,14 +129,14 @@ fn test_get_terminator() {
     let function = module.add_function("testing", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     // REVIEW: What's the difference between a terminator and last instruction?
     assert!(basic_block.get_terminator().is_none());
     assert!(basic_block.get_first_instruction().is_none());
     assert!(basic_block.get_last_instruction().is_none());
 
-    builder.build_return(None);
+    builder.build_return(None).unwrap();
 
     assert_eq!(
         basic_block.get_terminator().unwrap().get_opcode(),
@@ -193,8 +193,8 @@ fn test_rauw() {
     let entry = context.append_basic_block(fn_val, "entry");
     let bb1 = context.append_basic_block(fn_val, "bb1");
     let bb2 = context.append_basic_block(fn_val, "bb2");
-    builder.position_at_end(entry);
-    let branch_inst = builder.build_unconditional_branch(bb1);
+    builder.position_at_end(entry).unwrap();
+    let branch_inst = builder.build_unconditional_branch(bb1).unwrap();
 
     bb1.replace_all_uses_with(&bb1); // no-op
     bb1.replace_all_uses_with(&bb2);
@@ -213,11 +213,40 @@ fn test_get_first_use() {
     let entry = context.append_basic_block(fn_val, "entry");
     let bb1 = context.append_basic_block(fn_val, "bb1");
     let bb2 = context.append_basic_block(fn_val, "bb2");
-    builder.position_at_end(entry);
-    let branch_inst = builder.build_unconditional_branch(bb1);
+    builder.position_at_end(entry).unwrap();
+    let branch_inst = builder.build_unconditional_branch(bb1).unwrap();
 
     assert!(bb2.get_first_use().is_none());
     assert!(bb1.get_first_use().is_some());
     assert_eq!(bb1.get_first_use().unwrap().get_user(), branch_inst);
     assert!(bb1.get_first_use().unwrap().get_next_use().is_none());
 }
+
+#[llvm_versions(10.0..=latest)]
+#[test]
+fn test_reappend_removed_block() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    let function = module.add_function("testing", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let moved = context.append_basic_block(function, "moved");
+    let exit = context.append_basic_block(function, "exit");
+
+    builder.position_at_end(moved).unwrap();
+    builder.build_unconditional_branch(exit).unwrap();
+
+    assert!(moved.append_to_function(function).is_err());
+    assert!(moved.remove_from_function().is_ok());
+    assert_eq!(function.get_basic_blocks(), vec![entry, exit]);
+
+    // The removed block and its instructions are still intact
+    assert!(moved.get_terminator().is_some());
+    assert!(moved.append_to_function(function).is_ok());
+    assert_eq!(moved.get_parent().unwrap(), function);
+    assert_eq!(function.get_basic_blocks(), vec![entry, exit, moved]);
+}
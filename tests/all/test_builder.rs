@@ -1,6 +1,9 @@
+use inkwell::builder::Terminator;
 use inkwell::context::Context;
-use inkwell::values::BasicValue;
-use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, OptimizationLevel};
+use inkwell::passes::PassManager;
+use inkwell::targets::TargetData;
+use inkwell::values::{BasicValue, BasicValueEnum, InstructionOpcode};
+use inkwell::{AddressSpace, AtomicOrdering, AtomicRMWBinOp, IntPredicate, OptimizationLevel};
 
 use std::ptr::null;
 
@@ -16,18 +19,18 @@ fn test_build_call() {
     let function = module.add_function("get_pi", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let pi = f32_type.const_float(::std::f64::consts::PI);
 
-    builder.build_return(Some(&pi));
+    builder.build_return(Some(&pi)).unwrap();
 
     let function2 = module.add_function("wrapper", fn_type, None);
     let basic_block2 = context.append_basic_block(function2, "entry");
 
-    builder.position_at_end(basic_block2);
+    builder.position_at_end(basic_block2).unwrap();
 
-    let pi2_call_site = builder.build_call(function, &[], "get_pi");
+    let pi2_call_site = builder.build_call(function, &[], "get_pi").unwrap();
 
     assert!(!pi2_call_site.is_tail_call());
 
@@ -37,7 +40,7 @@ fn test_build_call() {
 
     let pi2 = pi2_call_site.try_as_basic_value().left().unwrap();
 
-    builder.build_return(Some(&pi2));
+    builder.build_return(Some(&pi2)).unwrap();
 
     assert!(module.verify().is_ok());
 
@@ -49,16 +52,19 @@ fn test_build_call() {
     let fn_ptr = function3.as_global_value().as_pointer_value();
     let fn_ptr_type = fn_ptr.get_type();
 
-    builder.position_at_end(basic_block3);
+    builder.position_at_end(basic_block3).unwrap();
 
-    let alloca = builder.build_alloca(fn_ptr_type, "alloca");
+    let alloca = builder.build_alloca(fn_ptr_type, "alloca").unwrap();
 
-    builder.build_store(alloca, fn_ptr);
+    builder.build_store(alloca, fn_ptr).unwrap();
 
-    let load = builder.build_load(alloca, "load").into_pointer_value();
+    let load = builder
+        .build_load(alloca, "load")
+        .unwrap()
+        .into_pointer_value();
 
-    builder.build_call(load, &[], "call");
-    builder.build_return(None);
+    builder.build_call(load, &[], "call").unwrap();
+    builder.build_return(None).unwrap();
 
     assert!(module.verify().is_ok());
 }
@@ -88,30 +94,36 @@ fn test_null_checked_ptr_ops() {
     let function = module.add_function("check_null_index1", fn_type, None);
     let entry = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let ptr = function.get_first_param().unwrap().into_pointer_value();
 
-    let is_null = builder.build_is_null(ptr, "is_null");
+    let is_null = builder.build_is_null(ptr, "is_null").unwrap();
 
     let ret_0 = context.append_basic_block(function, "ret_0");
     let ret_idx = context.append_basic_block(function, "ret_idx");
 
-    builder.build_conditional_branch(is_null, ret_0, ret_idx);
+    builder
+        .build_conditional_branch(is_null, ret_0, ret_idx)
+        .unwrap();
 
-    builder.position_at_end(ret_0);
-    builder.build_return(Some(&neg_one));
+    builder.position_at_end(ret_0).unwrap();
+    builder.build_return(Some(&neg_one)).unwrap();
 
-    builder.position_at_end(ret_idx);
+    builder.position_at_end(ret_idx).unwrap();
 
     // FIXME: This might not work if compiled on non 64bit devices. Ideally we'd
     // be able to create pointer sized ints easily
-    let ptr_as_int = builder.build_ptr_to_int(ptr, i64_type, "ptr_as_int");
-    let new_ptr_as_int = builder.build_int_add(ptr_as_int, one, "add");
-    let new_ptr = builder.build_int_to_ptr(new_ptr_as_int, i8_ptr_type, "int_as_ptr");
-    let index1 = builder.build_load(new_ptr, "deref");
+    let ptr_as_int = builder
+        .build_ptr_to_int(ptr, i64_type, "ptr_as_int")
+        .unwrap();
+    let new_ptr_as_int = builder.build_int_add(ptr_as_int, one, "add").unwrap();
+    let new_ptr = builder
+        .build_int_to_ptr(new_ptr_as_int, i8_ptr_type, "int_as_ptr")
+        .unwrap();
+    let index1 = builder.build_load(new_ptr, "deref").unwrap();
 
-    builder.build_return(Some(&index1));
+    builder.build_return(Some(&index1)).unwrap();
 
     // Here we're going to create a function that looks roughly like:
     // fn check_null_index2(ptr: *const i8) -> i8 {
@@ -125,30 +137,36 @@ fn test_null_checked_ptr_ops() {
     let function = module.add_function("check_null_index2", fn_type, None);
     let entry = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let ptr = function.get_first_param().unwrap().into_pointer_value();
 
-    let is_not_null = builder.build_is_not_null(ptr, "is_not_null");
+    let is_not_null = builder.build_is_not_null(ptr, "is_not_null").unwrap();
 
     let ret_idx = context.append_basic_block(function, "ret_idx");
     let ret_0 = context.append_basic_block(function, "ret_0");
 
-    builder.build_conditional_branch(is_not_null, ret_idx, ret_0);
+    builder
+        .build_conditional_branch(is_not_null, ret_idx, ret_0)
+        .unwrap();
 
-    builder.position_at_end(ret_0);
-    builder.build_return(Some(&neg_one));
+    builder.position_at_end(ret_0).unwrap();
+    builder.build_return(Some(&neg_one)).unwrap();
 
-    builder.position_at_end(ret_idx);
+    builder.position_at_end(ret_idx).unwrap();
 
     // FIXME: This might not work if compiled on non 64bit devices. Ideally we'd
     // be able to create pointer sized ints easily
-    let ptr_as_int = builder.build_ptr_to_int(ptr, i64_type, "ptr_as_int");
-    let new_ptr_as_int = builder.build_int_add(ptr_as_int, one, "add");
-    let new_ptr = builder.build_int_to_ptr(new_ptr_as_int, i8_ptr_type, "int_as_ptr");
-    let index1 = builder.build_load(new_ptr, "deref");
+    let ptr_as_int = builder
+        .build_ptr_to_int(ptr, i64_type, "ptr_as_int")
+        .unwrap();
+    let new_ptr_as_int = builder.build_int_add(ptr_as_int, one, "add").unwrap();
+    let new_ptr = builder
+        .build_int_to_ptr(new_ptr_as_int, i8_ptr_type, "int_as_ptr")
+        .unwrap();
+    let index1 = builder.build_load(new_ptr, "deref").unwrap();
 
-    builder.build_return(Some(&index1));
+    builder.build_return(Some(&index1)).unwrap();
 
     let execution_engine = module
         .create_jit_execution_engine(OptimizationLevel::None)
@@ -192,14 +210,14 @@ fn test_binary_ops() {
     let fn_value = module.add_function("and", fn_type, None);
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let left = fn_value.get_first_param().unwrap().into_int_value();
     let right = fn_value.get_last_param().unwrap().into_int_value();
 
-    let and = builder.build_and(left, right, "and_op");
+    let and = builder.build_and(left, right, "and_op").unwrap();
 
-    builder.build_return(Some(&and));
+    builder.build_return(Some(&and)).unwrap();
 
     // Here we're going to create an or function which looks roughly like:
     // fn or(left: bool, right: bool) -> bool {
@@ -209,14 +227,14 @@ fn test_binary_ops() {
     let fn_value = module.add_function("or", fn_type, None);
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let left = fn_value.get_first_param().unwrap().into_int_value();
     let right = fn_value.get_last_param().unwrap().into_int_value();
 
-    let or = builder.build_or(left, right, "or_op");
+    let or = builder.build_or(left, right, "or_op").unwrap();
 
-    builder.build_return(Some(&or));
+    builder.build_return(Some(&or)).unwrap();
 
     // Here we're going to create a xor function which looks roughly like:
     // fn xor(left: bool, right: bool) -> bool {
@@ -226,14 +244,14 @@ fn test_binary_ops() {
     let fn_value = module.add_function("xor", fn_type, None);
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let left = fn_value.get_first_param().unwrap().into_int_value();
     let right = fn_value.get_last_param().unwrap().into_int_value();
 
-    let xor = builder.build_xor(left, right, "xor_op");
+    let xor = builder.build_xor(left, right, "xor_op").unwrap();
 
-    builder.build_return(Some(&xor));
+    builder.build_return(Some(&xor)).unwrap();
 
     unsafe {
         type BoolFunc = unsafe extern "C" fn(bool, bool) -> bool;
@@ -293,20 +311,22 @@ fn test_switch() {
     let else_ = context.append_basic_block(fn_value, "else");
     let value = fn_value.get_first_param().unwrap().into_int_value();
 
-    builder.position_at_end(entry);
-    builder.build_switch(value, else_, &[(i8_zero, check), (i8_42, elif)]);
+    builder.position_at_end(entry).unwrap();
+    builder
+        .build_switch(value, else_, &[(i8_zero, check), (i8_42, elif)])
+        .unwrap();
 
-    builder.position_at_end(check);
-    builder.build_return(Some(&i8_one));
+    builder.position_at_end(check).unwrap();
+    builder.build_return(Some(&i8_one)).unwrap();
 
-    builder.position_at_end(elif);
-    builder.build_return(Some(&i8_255));
+    builder.position_at_end(elif).unwrap();
+    builder.build_return(Some(&i8_255)).unwrap();
 
-    builder.position_at_end(else_);
+    builder.position_at_end(else_).unwrap();
 
-    let double = builder.build_int_mul(value, i8_two, "double");
+    let double = builder.build_int_mul(value, i8_two, "double").unwrap();
 
-    builder.build_return(Some(&double));
+    builder.build_return(Some(&double)).unwrap();
 
     unsafe {
         let switch = execution_engine
@@ -342,11 +362,11 @@ fn test_bit_shifts() {
 
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
-    let shift = builder.build_left_shift(value, bits, "shl");
+    let shift = builder.build_left_shift(value, bits, "shl").unwrap();
 
-    builder.build_return(Some(&shift));
+    builder.build_return(Some(&shift)).unwrap();
 
     // Here we're going to create a function which looks roughly like:
     // fn right_shift(value: u8, bits: u8) -> u8 {
@@ -358,11 +378,13 @@ fn test_bit_shifts() {
 
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
-    let shift = builder.build_right_shift(value, bits, false, "shr");
+    let shift = builder
+        .build_right_shift(value, bits, false, "shr")
+        .unwrap();
 
-    builder.build_return(Some(&shift));
+    builder.build_return(Some(&shift)).unwrap();
 
     // Here we're going to create a function which looks roughly like:
     // fn right_shift(value: u8, bits: u8) -> u8 {
@@ -374,11 +396,11 @@ fn test_bit_shifts() {
 
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
-    let shift = builder.build_right_shift(value, bits, true, "shr");
+    let shift = builder.build_right_shift(value, bits, true, "shr").unwrap();
 
-    builder.build_return(Some(&shift));
+    builder.build_return(Some(&shift)).unwrap();
 
     unsafe {
         let left_shift = execution_engine
@@ -433,11 +455,11 @@ fn test_unconditional_branch() {
     let skipped_bb = context.append_basic_block(fn_value, "skipped");
     let end_bb = context.append_basic_block(fn_value, "end");
 
-    builder.position_at_end(entry_bb);
-    builder.build_unconditional_branch(end_bb);
+    builder.position_at_end(entry_bb).unwrap();
+    builder.build_unconditional_branch(end_bb).unwrap();
 
-    builder.position_at_end(skipped_bb);
-    builder.build_unreachable();
+    builder.position_at_end(skipped_bb).unwrap();
+    builder.build_unreachable().unwrap();
 }
 
 #[test]
@@ -469,11 +491,11 @@ fn test_no_builder_double_free2() {
     let fn_value = module.add_function("my_fn", fn_type, None);
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
     // FIXME: Builder segfaults when making build calls with different context
     // as of newer rust versions(late 2018+?). Maybe this isn't actually something
     // you're suppose to do in LLVM and LTO(?) has made it a more prominent issue?
-    // builder.build_unreachable();
+    // builder.build_unreachable().unwrap();
 
     // #[cfg(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8"))]
     // assert_eq!(*module.print_to_string(), *CString::new("; ModuleID = \'my_mod\'\n\ndefine void @my_fn() {\nentry:\n  unreachable\n}\n").unwrap());
@@ -500,11 +522,15 @@ fn test_vector_convert_ops() {
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
     let in_vec = fn_value.get_first_param().unwrap().into_vector_value();
-    let casted_vec = builder.build_int_cast(in_vec, int32_vec_type, "casted_vec");
-    let _uncasted_vec = builder.build_int_cast(casted_vec, int8_vec_type, "uncasted_vec");
-    builder.build_return(Some(&casted_vec));
+    let casted_vec = builder
+        .build_int_cast(in_vec, int32_vec_type, "casted_vec")
+        .unwrap();
+    let _uncasted_vec = builder
+        .build_int_cast(casted_vec, int8_vec_type, "uncasted_vec")
+        .unwrap();
+    builder.build_return(Some(&casted_vec)).unwrap();
     assert!(fn_value.verify(true));
 
     // Here we're building a function that takes in a <3 x f32> and returns it casted to and from a <3 x f16>
@@ -513,11 +539,15 @@ fn test_vector_convert_ops() {
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
     let in_vec = fn_value.get_first_param().unwrap().into_vector_value();
-    let casted_vec = builder.build_float_cast(in_vec, float16_vec_type, "casted_vec");
-    let _uncasted_vec = builder.build_float_cast(casted_vec, float32_vec_type, "uncasted_vec");
-    builder.build_return(Some(&casted_vec));
+    let casted_vec = builder
+        .build_float_cast(in_vec, float16_vec_type, "casted_vec")
+        .unwrap();
+    let _uncasted_vec = builder
+        .build_float_cast(casted_vec, float32_vec_type, "uncasted_vec")
+        .unwrap();
+    builder.build_return(Some(&casted_vec)).unwrap();
     assert!(fn_value.verify(true));
 
     // Here we're building a function that takes in a <3 x f32> and returns it casted to and from a <3 x i32>
@@ -526,12 +556,15 @@ fn test_vector_convert_ops() {
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
     let in_vec = fn_value.get_first_param().unwrap().into_vector_value();
-    let casted_vec = builder.build_float_to_signed_int(in_vec, int32_vec_type, "casted_vec");
-    let _uncasted_vec =
-        builder.build_signed_int_to_float(casted_vec, float32_vec_type, "uncasted_vec");
-    builder.build_return(Some(&casted_vec));
+    let casted_vec = builder
+        .build_float_to_signed_int(in_vec, int32_vec_type, "casted_vec")
+        .unwrap();
+    let _uncasted_vec = builder
+        .build_signed_int_to_float(casted_vec, float32_vec_type, "uncasted_vec")
+        .unwrap();
+    builder.build_return(Some(&casted_vec)).unwrap();
     assert!(fn_value.verify(true));
 }
 
@@ -556,13 +589,15 @@ fn test_vector_binary_ops() {
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
     let p1_vec = fn_value.get_first_param().unwrap().into_vector_value();
     let p2_vec = fn_value.get_nth_param(1).unwrap().into_vector_value();
     let p3_vec = fn_value.get_nth_param(2).unwrap().into_vector_value();
-    let added_vec = builder.build_int_add(p1_vec, p2_vec, "added_vec");
-    let added_vec = builder.build_int_add(added_vec, p3_vec, "added_vec");
-    builder.build_return(Some(&added_vec));
+    let added_vec = builder.build_int_add(p1_vec, p2_vec, "added_vec").unwrap();
+    let added_vec = builder
+        .build_int_add(added_vec, p3_vec, "added_vec")
+        .unwrap();
+    builder.build_return(Some(&added_vec)).unwrap();
     assert!(fn_value.verify(true));
 
     // Here we're building a function that takes in three <2 x f32>s and returns x * y / z as an
@@ -579,13 +614,17 @@ fn test_vector_binary_ops() {
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
     let p1_vec = fn_value.get_first_param().unwrap().into_vector_value();
     let p2_vec = fn_value.get_nth_param(1).unwrap().into_vector_value();
     let p3_vec = fn_value.get_nth_param(2).unwrap().into_vector_value();
-    let multiplied_vec = builder.build_float_mul(p1_vec, p2_vec, "multipled_vec");
-    let divided_vec = builder.build_float_div(multiplied_vec, p3_vec, "divided_vec");
-    builder.build_return(Some(&divided_vec));
+    let multiplied_vec = builder
+        .build_float_mul(p1_vec, p2_vec, "multipled_vec")
+        .unwrap();
+    let divided_vec = builder
+        .build_float_div(multiplied_vec, p3_vec, "divided_vec")
+        .unwrap();
+    builder.build_return(Some(&divided_vec)).unwrap();
     assert!(fn_value.verify(true));
 
     // Here we're building a function that takes two <2 x f32>s and a <2 x bool> and returns (x < y) * z
@@ -602,14 +641,17 @@ fn test_vector_binary_ops() {
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
     let p1_vec = fn_value.get_first_param().unwrap().into_vector_value();
     let p2_vec = fn_value.get_nth_param(1).unwrap().into_vector_value();
     let p3_vec = fn_value.get_nth_param(2).unwrap().into_vector_value();
-    let compared_vec =
-        builder.build_float_compare(inkwell::FloatPredicate::OLT, p1_vec, p2_vec, "compared_vec");
-    let multiplied_vec = builder.build_int_mul(compared_vec, p3_vec, "multiplied_vec");
-    builder.build_return(Some(&multiplied_vec));
+    let compared_vec = builder
+        .build_float_compare(inkwell::FloatPredicate::OLT, p1_vec, p2_vec, "compared_vec")
+        .unwrap();
+    let multiplied_vec = builder
+        .build_int_mul(compared_vec, p3_vec, "multiplied_vec")
+        .unwrap();
+    builder.build_return(Some(&multiplied_vec)).unwrap();
     assert!(fn_value.verify(true));
 }
 
@@ -628,11 +670,23 @@ fn test_vector_pointer_ops() {
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
     let in_vec = fn_value.get_first_param().unwrap().into_vector_value();
-    let ptr_vec = builder.build_int_to_ptr(in_vec, i8_ptr_vec_type, "ptr_vec");
-    let is_null_vec = builder.build_is_null(ptr_vec, "is_null_vec");
-    builder.build_return(Some(&is_null_vec));
+    let ptr_vec = builder
+        .build_int_to_ptr(in_vec, i8_ptr_vec_type, "ptr_vec")
+        .unwrap();
+
+    let i8_ptr_vec2_type = context.i8_type().ptr_type(AddressSpace::Zero).vec_type(2);
+
+    assert!(builder
+        .build_int_to_ptr(in_vec, i8_ptr_vec2_type, "ptr_vec2")
+        .is_err());
+    assert!(builder
+        .build_ptr_to_int(ptr_vec, context.i64_type().vec_type(2), "int_vec2")
+        .is_err());
+
+    let is_null_vec = builder.build_is_null(ptr_vec, "is_null_vec").unwrap();
+    builder.build_return(Some(&is_null_vec)).unwrap();
     assert!(fn_value.verify(true));
 }
 
@@ -650,11 +704,12 @@ fn test_insert_value() {
     let builder = context.create_builder();
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
-    let array_alloca = builder.build_alloca(array_type, "array_alloca");
+    let array_alloca = builder.build_alloca(array_type, "array_alloca").unwrap();
     let array = builder
         .build_load(array_alloca, "array_load")
+        .unwrap()
         .into_array_value();
     let const_int1 = i32_type.const_int(2, false);
     let const_int2 = i32_type.const_int(5, false);
@@ -675,10 +730,10 @@ fn test_insert_value() {
         .is_array_value());
     assert!(builder
         .build_insert_value(array, const_int3, 3, "insert")
-        .is_none());
+        .is_err());
     assert!(builder
         .build_insert_value(array, const_int3, 4, "insert")
-        .is_none());
+        .is_err());
 
     assert!(builder
         .build_extract_value(array, 0, "extract")
@@ -692,11 +747,12 @@ fn test_insert_value() {
         .build_extract_value(array, 2, "extract")
         .unwrap()
         .is_int_value());
-    assert!(builder.build_extract_value(array, 3, "extract").is_none());
+    assert!(builder.build_extract_value(array, 3, "extract").is_err());
 
-    let struct_alloca = builder.build_alloca(struct_type, "struct_alloca");
+    let struct_alloca = builder.build_alloca(struct_type, "struct_alloca").unwrap();
     let struct_value = builder
         .build_load(struct_alloca, "struct_load")
+        .unwrap()
         .into_struct_value();
 
     assert!(builder
@@ -709,10 +765,10 @@ fn test_insert_value() {
         .is_struct_value());
     assert!(builder
         .build_insert_value(struct_value, const_float, 2, "insert")
-        .is_none());
+        .is_err());
     assert!(builder
         .build_insert_value(struct_value, const_float, 3, "insert")
-        .is_none());
+        .is_err());
 
     assert!(builder
         .build_extract_value(struct_value, 0, "extract")
@@ -724,12 +780,12 @@ fn test_insert_value() {
         .is_float_value());
     assert!(builder
         .build_extract_value(struct_value, 2, "extract")
-        .is_none());
+        .is_err());
     assert!(builder
         .build_extract_value(struct_value, 3, "extract")
-        .is_none());
+        .is_err());
 
-    builder.build_return(None);
+    builder.build_return(None).unwrap();
 
     assert!(module.verify().is_ok());
 }
@@ -793,7 +849,7 @@ fn run_memcpy_on<'ctx>(
     let builder = context.create_builder();
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let len_value = i64_type.const_int(array_len as u64, false);
     let array_ptr = builder
@@ -803,10 +859,14 @@ fn run_memcpy_on<'ctx>(
     // Initialize the array with the values [1, 2, 3, 4]
     for index in 0..4 {
         let index_val = i32_type.const_int(index, false);
-        let elem_ptr = unsafe { builder.build_in_bounds_gep(array_ptr, &[index_val], "index") };
+        let elem_ptr = unsafe {
+            builder
+                .build_in_bounds_gep(array_ptr, &[index_val], "index")
+                .unwrap()
+        };
         let int_val = i32_type.const_int(index + 1, false);
 
-        builder.build_store(elem_ptr, int_val);
+        builder.build_store(elem_ptr, int_val).unwrap();
     }
 
     // Memcpy the first half of the array over the second half of the array.
@@ -814,11 +874,15 @@ fn run_memcpy_on<'ctx>(
     let bytes_to_copy = elems_to_copy * std::mem::size_of::<i32>();
     let size_val = i64_type.const_int(bytes_to_copy as u64, false);
     let index_val = i32_type.const_int(2, false);
-    let dest_ptr = unsafe { builder.build_in_bounds_gep(array_ptr, &[index_val], "index") };
+    let dest_ptr = unsafe {
+        builder
+            .build_in_bounds_gep(array_ptr, &[index_val], "index")
+            .unwrap()
+    };
 
     builder.build_memcpy(dest_ptr, alignment, array_ptr, alignment, size_val)?;
 
-    builder.build_return(Some(&array_ptr));
+    builder.build_return(Some(&array_ptr)).unwrap();
 
     Ok(())
 }
@@ -867,7 +931,7 @@ fn run_memmove_on<'ctx>(
     let builder = context.create_builder();
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let len_value = i64_type.const_int(array_len as u64, false);
     let array_ptr = builder
@@ -877,10 +941,14 @@ fn run_memmove_on<'ctx>(
     // Initialize the array with the values [1, 2, 3, 4]
     for index in 0..4 {
         let index_val = i32_type.const_int(index, false);
-        let elem_ptr = unsafe { builder.build_in_bounds_gep(array_ptr, &[index_val], "index") };
+        let elem_ptr = unsafe {
+            builder
+                .build_in_bounds_gep(array_ptr, &[index_val], "index")
+                .unwrap()
+        };
         let int_val = i32_type.const_int(index + 1, false);
 
-        builder.build_store(elem_ptr, int_val);
+        builder.build_store(elem_ptr, int_val).unwrap();
     }
 
     // Memcpy the first half of the array over the second half of the array.
@@ -888,11 +956,15 @@ fn run_memmove_on<'ctx>(
     let bytes_to_copy = elems_to_copy * std::mem::size_of::<i32>();
     let size_val = i64_type.const_int(bytes_to_copy as u64, false);
     let index_val = i32_type.const_int(2, false);
-    let dest_ptr = unsafe { builder.build_in_bounds_gep(array_ptr, &[index_val], "index") };
+    let dest_ptr = unsafe {
+        builder
+            .build_in_bounds_gep(array_ptr, &[index_val], "index")
+            .unwrap()
+    };
 
     builder.build_memmove(dest_ptr, alignment, array_ptr, alignment, size_val)?;
 
-    builder.build_return(Some(&array_ptr));
+    builder.build_return(Some(&array_ptr)).unwrap();
 
     Ok(())
 }
@@ -956,15 +1028,23 @@ fn test_bitcast() {
     let i32_ptr_arg = fn_value.get_nth_param(3).unwrap();
     let f64_arg = fn_value.get_nth_param(4).unwrap();
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
-    let cast = builder.build_bitcast(i32_arg, f32_type, "i32tof32");
+    let cast = builder
+        .build_bitcast(i32_arg, f32_type, "i32tof32")
+        .unwrap();
 
-    builder.build_bitcast(f32_arg, f32_type, "f32tof32");
-    builder.build_bitcast(i32_vec_arg, i64_type, "2xi32toi64");
-    builder.build_bitcast(i32_ptr_arg, i64_ptr_type, "i32*toi64*");
+    builder
+        .build_bitcast(f32_arg, f32_type, "f32tof32")
+        .unwrap();
+    builder
+        .build_bitcast(i32_vec_arg, i64_type, "2xi32toi64")
+        .unwrap();
+    builder
+        .build_bitcast(i32_ptr_arg, i64_ptr_type, "i32*toi64*")
+        .unwrap();
 
-    builder.build_return(None);
+    builder.build_return(None).unwrap();
 
     assert!(
         module.verify().is_ok(),
@@ -973,8 +1053,63 @@ fn test_bitcast() {
 
     let first_iv = cast.as_instruction_value().unwrap();
 
-    builder.position_before(&first_iv);
-    builder.build_bitcast(f64_arg, i64_type, "f64toi64");
+    builder.position_before(&first_iv).unwrap();
+    builder
+        .build_bitcast(f64_arg, i64_type, "f64toi64")
+        .unwrap();
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_bitcast_validation() {
+    let context = Context::create();
+    let module = context.create_module("bc");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let f32_type = context.f32_type();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Zero);
+    let struct_type = context.struct_type(&[i32_type.into()], false);
+    let arg_types = [
+        i32_type.into(),
+        struct_type.into(),
+        i32_ptr_type.into(),
+        i64_type.into(),
+    ];
+    let fn_type = void_type.fn_type(&arg_types, false);
+    let fn_value = module.add_function("bc", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let i32_arg = fn_value.get_first_param().unwrap();
+    let struct_arg = fn_value.get_nth_param(1).unwrap();
+    let i32_ptr_arg = fn_value.get_nth_param(2).unwrap();
+    let i64_arg = fn_value.get_nth_param(3).unwrap();
+
+    builder.position_at_end(entry).unwrap();
+
+    let cast = builder
+        .build_bitcast(i32_arg, f32_type, "i32tof32")
+        .unwrap();
+
+    assert!(cast.is_float_value());
+    assert!(builder
+        .build_bitcast(struct_arg, i32_type, "structtoi32")
+        .is_err());
+    assert!(builder
+        .build_bitcast(i32_arg, struct_type, "i32tostruct")
+        .is_err());
+    assert!(builder
+        .build_bitcast(i32_ptr_arg, i64_type, "i32*toi64")
+        .is_err());
+    assert!(builder
+        .build_bitcast(i64_arg, i32_ptr_type, "i64toi32*")
+        .is_err());
+    assert!(builder
+        .build_bitcast(i64_arg, f32_type, "i64tof32")
+        .is_err());
+
+    builder.build_return(None).unwrap();
 
     assert!(module.verify().is_ok());
 }
@@ -989,7 +1124,7 @@ fn test_atomicrmw() {
     let fn_value = module.add_function("", fn_type, None);
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let i32_type = context.i32_type();
     let i64_type = context.i64_type();
@@ -1048,7 +1183,7 @@ fn test_cmpxchg() {
     let fn_value = module.add_function("", fn_type, None);
     let entry = context.append_basic_block(fn_value, "entry");
     let builder = context.create_builder();
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let i32_type = context.i32_type();
     let i64_type = context.i64_type();
@@ -1203,7 +1338,7 @@ fn test_safe_struct_gep() {
     let fn_value = module.add_function("", fn_type, None);
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
+    builder.position_at_end(entry).unwrap();
 
     let i32_ptr = fn_value.get_first_param().unwrap().into_pointer_value();
     let struct_ptr = fn_value.get_last_param().unwrap().into_pointer_value();
@@ -1220,3 +1355,1054 @@ fn test_safe_struct_gep() {
         .build_struct_gep(struct_ptr, 2, "struct_gep")
         .is_err());
 }
+
+#[test]
+fn test_address_space_casts() {
+    let context = Context::create();
+    let module = context.create_module("av");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let generic_ptr_type = i32_type.ptr_type(AddressSpace::Zero);
+    let global_ptr_type = i32_type.ptr_type(AddressSpace::One);
+    let fn_type = void_type.fn_type(&[generic_ptr_type.into()], false);
+    let fn_value = module.add_function("cast_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let ptr_param = fn_value.get_first_param().unwrap().into_pointer_value();
+
+    // 64 bit pointers in address space zero and 32 bit pointers in address space one
+    let target_data = TargetData::create("e-p:64:64-p1:32:32");
+
+    module.set_data_layout(&target_data.get_data_layout());
+    builder.position_at_end(entry).unwrap();
+
+    let global_ptr = builder
+        .build_address_space_cast(ptr_param, global_ptr_type, "global_ptr")
+        .unwrap();
+
+    assert_eq!(global_ptr.get_type(), global_ptr_type);
+    assert!(builder
+        .build_address_space_cast(ptr_param, generic_ptr_type, "generic_ptr")
+        .is_err());
+
+    // Ints of any width are truncated or zero extended unless widths are checked
+    assert!(builder
+        .build_ptr_to_int(global_ptr, i64_type, "int")
+        .is_ok());
+    assert!(builder
+        .build_int_to_ptr(i64_type.const_zero(), global_ptr_type, "ptr")
+        .is_ok());
+
+    builder.set_check_ptr_int_widths(true);
+
+    assert!(builder.build_ptr_to_int(ptr_param, i64_type, "int").is_ok());
+    assert!(builder
+        .build_ptr_to_int(global_ptr, i32_type, "int")
+        .is_ok());
+    assert!(builder
+        .build_ptr_to_int(global_ptr, i64_type, "int")
+        .is_err());
+
+    let int = builder
+        .build_ptr_to_int(global_ptr, i32_type, "int")
+        .unwrap();
+
+    assert!(builder
+        .build_int_to_ptr(int, generic_ptr_type, "ptr")
+        .is_err());
+    assert!(builder
+        .build_int_to_ptr(int, global_ptr_type, "ptr")
+        .is_ok());
+
+    builder.build_return(None).unwrap();
+
+    assert!(module.verify().is_ok());
+}
+
+#[llvm_versions(8.0..=latest)]
+#[test]
+fn test_saturating_arithmetic() {
+    let context = Context::create();
+    let module = context.create_module("sat");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let fn_type = i8_type.fn_type(&[], false);
+    let fn_value = module.add_function("sat_add", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let i8_hundred = i8_type.const_int(100, false);
+
+    assert!(builder
+        .build_sadd_sat(i8_hundred, i8_hundred, "sum")
+        .is_err());
+
+    builder.position_at_end(entry).unwrap();
+
+    let sum = builder
+        .build_sadd_sat(i8_hundred, i8_hundred, "sum")
+        .unwrap();
+
+    builder
+        .build_uadd_sat(i8_hundred, i8_hundred, "usum")
+        .unwrap();
+    builder
+        .build_ssub_sat(i8_hundred, i8_hundred, "diff")
+        .unwrap();
+    builder
+        .build_usub_sat(i8_hundred, i8_hundred, "udiff")
+        .unwrap();
+    builder.build_return(Some(&sum)).unwrap();
+
+    assert!(module.get_function("llvm.sadd.sat.i8").is_some());
+    assert!(module.verify().is_ok());
+
+    let pass_manager = PassManager::create(());
+
+    pass_manager.add_instruction_combining_pass();
+    pass_manager.run_on(&module);
+
+    let ret = entry.get_terminator().unwrap();
+    let ret_value = ret.get_operand(0).unwrap().left().unwrap().into_int_value();
+
+    assert_eq!(ret_value.get_sign_extended_constant(), Some(127));
+}
+
+#[test]
+fn test_overflow_arithmetic() {
+    let context = Context::create();
+    let module = context.create_module("overflow");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("add", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let lhs = fn_value.get_first_param().unwrap().into_int_value();
+    let rhs = fn_value.get_last_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry).unwrap();
+
+    let signed = builder
+        .build_sadd_with_overflow(lhs, rhs, "signed")
+        .unwrap();
+    let unsigned = builder
+        .build_uadd_with_overflow(lhs, rhs, "unsigned")
+        .unwrap();
+
+    assert_eq!(signed.get_type().count_fields(), 2);
+
+    let field_types = unsigned.get_type().get_field_types();
+
+    assert_eq!(field_types[0].into_int_type(), i32_type);
+    assert_eq!(field_types[1].into_int_type(), context.bool_type());
+
+    let sum = builder.build_extract_value(signed, 0, "sum").unwrap();
+
+    builder.build_return(Some(&sum)).unwrap();
+
+    assert!(module.get_function("llvm.sadd.with.overflow.i32").is_some());
+    assert!(module.get_function("llvm.uadd.with.overflow.i32").is_some());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_landing_pad() {
+    let context = Context::create();
+    let module = context.create_module("eh");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Zero);
+    let exception_type = context.struct_type(&[i8_ptr_type.into(), i32_type.into()], false);
+    let personality_type = i32_type.fn_type(&[], true);
+    let personality = module.add_function("__gxx_personality_v0", personality_type, None);
+    let fn_type = void_type.fn_type(&[], false);
+    let may_throw = module.add_function("may_throw", fn_type, None);
+    let fn_value = module.add_function("catches", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let then = context.append_basic_block(fn_value, "then");
+    let catch = context.append_basic_block(fn_value, "catch");
+
+    fn_value.set_personality_function(personality);
+
+    builder.position_at_end(entry).unwrap();
+    builder
+        .build_invoke(may_throw, &[], then, catch, "call")
+        .unwrap();
+
+    builder.position_at_end(then).unwrap();
+    builder.build_return(None).unwrap();
+
+    builder.position_at_end(catch).unwrap();
+
+    assert!(builder
+        .build_landing_pad(exception_type, personality, &[], false, "exception")
+        .is_err());
+
+    let not_a_fn_ptr = i8_ptr_type.const_null();
+
+    assert!(builder
+        .build_landing_pad(exception_type, not_a_fn_ptr, &[], true, "exception")
+        .is_err());
+
+    // A typeinfo of null catches every exception
+    let catch_all = i8_ptr_type.const_null();
+    let exception = builder
+        .build_landing_pad(
+            exception_type,
+            personality,
+            &[catch_all.into()],
+            false,
+            "exception",
+        )
+        .unwrap();
+
+    assert!(exception.is_struct_value());
+
+    let resume = builder.build_resume(exception).unwrap();
+
+    assert_eq!(resume.get_opcode(), InstructionOpcode::Resume);
+    assert!(module.verify().is_ok());
+}
+
+#[llvm_versions(6.0..=latest)]
+#[test]
+fn test_funclet_exception_handling() {
+    let context = Context::create();
+    let module = context.create_module("seh");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Zero);
+    let personality_type = i32_type.fn_type(&[], true);
+    let personality = module.add_function("__CxxFrameHandler3", personality_type, None);
+    let fn_type = void_type.fn_type(&[], false);
+    let may_throw = module.add_function("may_throw", fn_type, None);
+    let fn_value = module.add_function("catches", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let exit = context.append_basic_block(fn_value, "exit");
+    let dispatch = context.append_basic_block(fn_value, "dispatch");
+    let handler = context.append_basic_block(fn_value, "handler");
+    let cleanup = context.append_basic_block(fn_value, "cleanup");
+
+    fn_value.set_personality_function(personality);
+
+    assert!(builder
+        .build_catch_switch(None, None, &[handler], "switch")
+        .is_err());
+
+    builder.position_at_end(entry).unwrap();
+    builder
+        .build_invoke(may_throw, &[], exit, dispatch, "call")
+        .unwrap();
+
+    builder.position_at_end(exit).unwrap();
+    builder.build_return(None).unwrap();
+
+    // dispatch:
+    //   %switch = catchswitch within none [label %handler] unwind label %cleanup
+    builder.position_at_end(dispatch).unwrap();
+
+    let catch_switch = builder
+        .build_catch_switch(None, Some(cleanup), &[handler], "switch")
+        .unwrap();
+
+    assert_eq!(catch_switch.get_opcode(), InstructionOpcode::CatchSwitch);
+
+    // handler:
+    //   %catch = catchpad within %switch [i8* null, i32 64, i8* null]
+    //   catchret from %catch to label %exit
+    builder.position_at_end(handler).unwrap();
+
+    let catch_args: [BasicValueEnum; 3] = [
+        i8_ptr_type.const_null().into(),
+        i32_type.const_int(64, false).into(),
+        i8_ptr_type.const_null().into(),
+    ];
+
+    assert!(builder
+        .build_catch_pad(exit.get_first_instruction().unwrap(), &catch_args, "catch")
+        .is_err());
+
+    let catch_pad = builder
+        .build_catch_pad(catch_switch, &catch_args, "catch")
+        .unwrap();
+
+    assert!(builder.build_catch_ret(catch_switch, exit).is_err());
+
+    let catch_ret = builder.build_catch_ret(catch_pad, exit).unwrap();
+
+    assert_eq!(catch_pad.get_opcode(), InstructionOpcode::CatchPad);
+    assert_eq!(catch_ret.get_opcode(), InstructionOpcode::CatchRet);
+
+    // cleanup:
+    //   %pad = cleanuppad within none []
+    //   cleanupret from %pad unwind to caller
+    builder.position_at_end(cleanup).unwrap();
+
+    let cleanup_pad = builder.build_cleanup_pad(None, &[], "pad").unwrap();
+
+    assert!(builder.build_cleanup_ret(catch_pad, None).is_err());
+
+    let cleanup_ret = builder.build_cleanup_ret(cleanup_pad, None).unwrap();
+
+    assert_eq!(cleanup_pad.get_opcode(), InstructionOpcode::CleanupPad);
+    assert_eq!(cleanup_ret.get_opcode(), InstructionOpcode::CleanupRet);
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_gep_address_space_index_width() {
+    let context = Context::create();
+    let module = context.create_module("av");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let array_type = i32_type.array_type(4);
+    let struct_type = context.struct_type(&[i64_type.into(), array_type.into()], false);
+    let generic_ptr_type = array_type.ptr_type(AddressSpace::Zero);
+    let heap_ptr_type = array_type.ptr_type(AddressSpace::One);
+    let heap_struct_ptr_type = struct_type.ptr_type(AddressSpace::One);
+    let fn_type = void_type.fn_type(
+        &[
+            generic_ptr_type.into(),
+            heap_ptr_type.into(),
+            heap_struct_ptr_type.into(),
+        ],
+        false,
+    );
+    let fn_value = module.add_function("gep_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let generic_ptr = fn_value.get_nth_param(0).unwrap().into_pointer_value();
+    let heap_ptr = fn_value.get_nth_param(1).unwrap().into_pointer_value();
+    let heap_struct_ptr = fn_value.get_nth_param(2).unwrap().into_pointer_value();
+
+    // 64 bit pointers in address space zero and 32 bit pointers in address space one
+    let target_data = TargetData::create("e-p:64:64-p1:32:32");
+
+    module.set_data_layout(&target_data.get_data_layout());
+    builder.position_at_end(entry).unwrap();
+
+    let i32_zero = i32_type.const_zero();
+    let i32_two = i32_type.const_int(2, false);
+    let i64_zero = i64_type.const_zero();
+    let i64_two = i64_type.const_int(2, false);
+
+    unsafe {
+        assert!(builder
+            .build_gep(generic_ptr, &[i64_zero, i32_two], "generic_gep")
+            .is_ok());
+        assert!(builder
+            .build_gep(heap_ptr, &[i32_zero, i32_two], "heap_gep")
+            .is_ok());
+        assert!(builder
+            .build_in_bounds_gep(heap_ptr, &[i32_zero, i32_two], "heap_gep")
+            .is_ok());
+        assert!(builder
+            .build_gep(heap_ptr, &[i32_zero, i64_two], "heap_gep")
+            .is_err());
+        assert!(builder
+            .build_in_bounds_gep(heap_ptr, &[i64_zero, i32_two], "heap_gep")
+            .is_err());
+
+        // Struct fields are always indexed by i32s, whatever the width of the pointers
+        assert!(builder
+            .build_gep(
+                heap_struct_ptr,
+                &[i32_zero, i32_type.const_int(1, false), i32_two],
+                "field_gep"
+            )
+            .is_ok());
+
+        // Indexes into the array after the struct field are still checked
+        assert!(builder
+            .build_gep(
+                heap_struct_ptr,
+                &[i32_zero, i32_type.const_int(1, false), i64_two],
+                "field_gep"
+            )
+            .is_err());
+        assert!(builder
+            .build_gep(
+                heap_struct_ptr,
+                &[
+                    i32_zero,
+                    i32_type.const_int(1, false),
+                    context.i8_type().const_int(2, false)
+                ],
+                "field_gep"
+            )
+            .is_err());
+    }
+
+    builder.build_return(None).unwrap();
+
+    assert!(module.verify().is_ok());
+}
+
+#[llvm_versions(10.0..=latest)]
+#[test]
+fn test_ptrmask() {
+    let context = Context::create();
+    let module = context.create_module("ptrmask");
+    let builder = context.create_builder();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Zero);
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let fn_type = i8_ptr_type.fn_type(&[i8_ptr_type.into()], false);
+    let fn_value = module.add_function("align_down", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    let mask = i64_type.const_int(!0xF, false);
+
+    assert!(builder.build_ptrmask(ptr, mask, "aligned_ptr").is_err());
+
+    let target_data = TargetData::create("e-p:64:64");
+
+    module.set_data_layout(&target_data.get_data_layout());
+    builder.position_at_end(entry).unwrap();
+
+    assert!(builder
+        .build_ptrmask(ptr, i32_type.const_int(!0xF, false), "aligned_ptr")
+        .is_err());
+
+    let aligned_ptr = builder.build_ptrmask(ptr, mask, "aligned_ptr").unwrap();
+
+    assert_eq!(aligned_ptr.get_type(), i8_ptr_type);
+
+    builder.build_return(Some(&aligned_ptr)).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(module.verify().is_ok());
+    assert!(
+        ir.contains("call i8* @llvm.ptrmask.p0i8.i64(i8* %0, i64 -16)"),
+        "{}",
+        ir
+    );
+}
+
+#[test]
+fn test_assume_and_expect() {
+    let context = Context::create();
+    let module = context.create_module("hints");
+    let builder = context.create_builder();
+    let bool_type = context.bool_type();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("hints", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let likely = context.append_basic_block(fn_value, "likely");
+    let unlikely = context.append_basic_block(fn_value, "unlikely");
+    let value = fn_value.get_first_param().unwrap().into_int_value();
+
+    assert!(builder.build_assume(bool_type.const_all_ones()).is_err());
+
+    builder.position_at_end(entry).unwrap();
+
+    assert!(builder.build_assume(value).is_err());
+
+    let is_positive = builder
+        .build_int_compare(
+            IntPredicate::SGT,
+            value,
+            i32_type.const_zero(),
+            "is_positive",
+        )
+        .unwrap();
+    let assume = builder.build_assume(is_positive).unwrap();
+
+    assert_eq!(assume.get_opcode(), InstructionOpcode::Call);
+
+    let is_small = builder
+        .build_int_compare(
+            IntPredicate::SLT,
+            value,
+            i32_type.const_int(100, false),
+            "is_small",
+        )
+        .unwrap();
+
+    assert!(builder
+        .build_expect(is_small, i32_type.const_zero(), "expected")
+        .is_err());
+    assert!(builder
+        .build_expect(is_small, is_positive, "expected")
+        .is_err());
+
+    let expected = builder
+        .build_expect(is_small, bool_type.const_all_ones(), "expected")
+        .unwrap();
+
+    assert_eq!(expected.get_type(), bool_type);
+
+    builder
+        .build_conditional_branch(expected, likely, unlikely)
+        .unwrap();
+    builder.position_at_end(likely).unwrap();
+    builder.build_return(Some(&value)).unwrap();
+    builder.position_at_end(unlikely).unwrap();
+    builder.build_return(Some(&i32_type.const_zero())).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(module.verify().is_ok());
+    assert!(
+        ir.contains("call void @llvm.assume(i1 %is_positive)"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("%expected = call i1 @llvm.expect.i1(i1 %is_small, i1 true)"),
+        "{}",
+        ir
+    );
+}
+
+#[test]
+fn test_lifetime_markers() {
+    let context = Context::create();
+    let module = context.create_module("lifetime");
+    let builder = context.create_builder();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("scoped", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry).unwrap();
+
+    let slot = builder.build_alloca(i32_type, "slot").unwrap();
+    let buffer = builder
+        .build_alloca(i8_type.array_type(16), "buffer")
+        .unwrap();
+
+    let start = builder.build_lifetime_start(4, slot).unwrap();
+
+    assert_eq!(start.get_opcode(), InstructionOpcode::Call);
+
+    builder.build_store(slot, i32_type.const_zero()).unwrap();
+    builder.build_lifetime_end(4, slot).unwrap();
+    builder.build_lifetime_start(u64::MAX, buffer).unwrap();
+    builder.build_lifetime_end(u64::MAX, buffer).unwrap();
+    builder.build_return(None).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(module.verify().is_ok());
+    assert!(
+        ir.contains("call void @llvm.lifetime.start.p0i32(i64 4, i32* %slot)"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("call void @llvm.lifetime.end.p0i32(i64 4, i32* %slot)"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("call void @llvm.lifetime.start.p0a16i8(i64 -1, [16 x i8]* %buffer)"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("call void @llvm.lifetime.end.p0a16i8(i64 -1, [16 x i8]* %buffer)"),
+        "{}",
+        ir
+    );
+}
+
+#[llvm_versions(8.0..=latest)]
+#[test]
+fn test_build_indirect_call() {
+    let context = Context::create();
+    let module = context.create_module("indirect");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let callee_type = i32_type.fn_type(&[i32_type.into()], false);
+    let callee_ptr_type = callee_type.ptr_type(AddressSpace::Zero);
+    let callee = module.add_function("double", callee_type, None);
+
+    builder
+        .position_at_end(context.append_basic_block(callee, "entry"))
+        .unwrap();
+
+    let arg = callee.get_first_param().unwrap().into_int_value();
+    let doubled = builder.build_int_add(arg, arg, "doubled").unwrap();
+
+    builder.build_return(Some(&doubled)).unwrap();
+
+    let callee_global = module.add_global(callee_ptr_type, None, "callee");
+
+    callee_global
+        .set_initializer(&callee.as_global_value().as_pointer_value())
+        .unwrap();
+
+    let fn_value = module.add_function("caller", i32_type.fn_type(&[], false), None);
+
+    builder
+        .position_at_end(context.append_basic_block(fn_value, "entry"))
+        .unwrap();
+
+    let fn_ptr = builder
+        .build_load(callee_global.as_pointer_value(), "fn_ptr")
+        .unwrap()
+        .into_pointer_value();
+    let arg = i32_type.const_int(21, false);
+
+    assert!(builder
+        .build_indirect_call(
+            i64_type.fn_type(&[i32_type.into()], false),
+            fn_ptr,
+            &[arg.into()],
+            "call"
+        )
+        .is_err());
+    assert!(builder
+        .build_indirect_call(callee_type, fn_ptr, &[], "call")
+        .is_err());
+    assert!(builder
+        .build_indirect_call(
+            callee_type,
+            fn_ptr,
+            &[i64_type.const_int(21, false).into()],
+            "call"
+        )
+        .is_err());
+
+    let call_site = builder
+        .build_indirect_call(callee_type, fn_ptr, &[arg.into()], "call")
+        .unwrap();
+    let ret_val = call_site.try_as_basic_value().left().unwrap();
+
+    assert_eq!(ret_val.get_type(), i32_type.into());
+
+    builder.build_return(Some(&ret_val)).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(module.verify().is_ok());
+    assert!(ir.contains("%call = call i32 %fn_ptr(i32 21)"), "{}", ir);
+}
+
+#[test]
+fn test_null_checks_on_constants() {
+    let context = Context::create();
+    let module = context.create_module("null_checks");
+    let builder = context.create_builder();
+    let bool_type = context.bool_type();
+    let i32_type = context.i32_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Zero);
+    let fn_type = bool_type.fn_type(&[i32_ptr_type.into()], false);
+    let fn_value = module.add_function("null_checks", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let global = module.add_global(i32_type, None, "my_global");
+
+    global.set_initializer(&i32_type.const_zero()).unwrap();
+    builder.position_at_end(entry).unwrap();
+
+    let null = i32_ptr_type.const_null();
+    let non_null = global.as_pointer_value();
+
+    let null_is_null = builder.build_is_null(null, "null_is_null").unwrap();
+    let null_is_not_null = builder.build_is_not_null(null, "null_is_not_null").unwrap();
+    let non_null_is_null = builder.build_is_null(non_null, "non_null_is_null").unwrap();
+    let non_null_is_not_null = builder
+        .build_is_not_null(non_null, "non_null_is_not_null")
+        .unwrap();
+
+    for value in &[
+        null_is_null,
+        null_is_not_null,
+        non_null_is_null,
+        non_null_is_not_null,
+    ] {
+        assert_eq!(value.get_type(), bool_type);
+        assert!(value.is_const());
+    }
+
+    assert_eq!(null_is_null.get_zero_extended_constant(), Some(1));
+    assert_eq!(null_is_not_null.get_zero_extended_constant(), Some(0));
+    assert_eq!(non_null_is_null.get_zero_extended_constant(), Some(0));
+    assert_eq!(non_null_is_not_null.get_zero_extended_constant(), Some(1));
+
+    let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    let is_null = builder.build_is_null(ptr, "is_null").unwrap();
+
+    assert_eq!(is_null.get_type(), bool_type);
+    assert!(!is_null.is_const());
+
+    builder.build_return(Some(&is_null)).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(module.verify().is_ok());
+    assert!(ir.contains("%is_null = icmp eq i32* %0, null"), "{}", ir);
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_vector_reductions() {
+    let context = Context::create();
+    let module = context.create_module("reduce");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let f32_type = context.f32_type();
+    let f64_type = context.f64_type();
+    let i32_vec_type = i32_type.vec_type(4);
+    let f32_vec_type = f32_type.vec_type(4);
+    let fn_type = i32_type.fn_type(&[i32_vec_type.into(), f32_vec_type.into()], false);
+    let fn_value = module.add_function("reduce", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let ints = fn_value.get_first_param().unwrap().into_vector_value();
+    let floats = fn_value.get_last_param().unwrap().into_vector_value();
+
+    assert!(builder.build_vector_reduce_add(ints, "sum").is_err());
+
+    builder.position_at_end(entry).unwrap();
+
+    assert!(builder.build_vector_reduce_add(floats, "sum").is_err());
+    assert!(builder
+        .build_vector_reduce_fadd(f64_type.const_zero(), floats, "fsum")
+        .is_err());
+
+    let sum = builder.build_vector_reduce_add(ints, "sum").unwrap();
+
+    assert_eq!(sum.get_type(), i32_type);
+
+    builder.build_vector_reduce_mul(ints, "product").unwrap();
+    builder.build_vector_reduce_and(ints, "and").unwrap();
+    builder.build_vector_reduce_or(ints, "or").unwrap();
+    builder.build_vector_reduce_xor(ints, "xor").unwrap();
+
+    let fsum = builder
+        .build_vector_reduce_fadd(f32_type.const_zero(), floats, "fsum")
+        .unwrap();
+    let fproduct = builder
+        .build_vector_reduce_fmul(f32_type.const_float(1.), floats, "fproduct")
+        .unwrap();
+
+    assert_eq!(fsum.get_type(), f32_type);
+    assert_eq!(fproduct.get_type(), f32_type);
+
+    builder.build_return(Some(&sum)).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(module.verify().is_ok());
+    assert!(
+        ir.contains("%sum = call i32 @llvm.experimental.vector.reduce.add.v4i32(<4 x i32> %0)"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("@llvm.experimental.vector.reduce.mul.v4i32"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("@llvm.experimental.vector.reduce.and.v4i32"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("@llvm.experimental.vector.reduce.or.v4i32"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("@llvm.experimental.vector.reduce.xor.v4i32"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("%fsum = call float @llvm.experimental.vector.reduce.v2.fadd.f32.v4f32(float 0.000000e+00, <4 x float> %1)"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("@llvm.experimental.vector.reduce.v2.fmul.f32.v4f32"),
+        "{}",
+        ir
+    );
+}
+
+#[test]
+fn test_select_condition_validation() {
+    let context = Context::create();
+    let module = context.create_module("select");
+    let builder = context.create_builder();
+    let bool_type = context.bool_type();
+    let i32_type = context.i32_type();
+    let i32_vec_type = i32_type.vec_type(4);
+    let fn_type = i32_vec_type.fn_type(
+        &[
+            bool_type.vec_type(4).into(),
+            bool_type.vec_type(2).into(),
+            i32_vec_type.into(),
+            i32_vec_type.into(),
+        ],
+        false,
+    );
+    let fn_value = module.add_function("select", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let mask = fn_value.get_nth_param(0).unwrap().into_vector_value();
+    let short_mask = fn_value.get_nth_param(1).unwrap().into_vector_value();
+    let lhs = fn_value.get_nth_param(2).unwrap().into_vector_value();
+    let rhs = fn_value.get_nth_param(3).unwrap().into_vector_value();
+
+    builder.position_at_end(entry).unwrap();
+
+    assert!(builder.build_select(short_mask, lhs, rhs, "bad").is_err());
+    assert!(builder
+        .build_select(i32_type.const_zero(), lhs, rhs, "bad")
+        .is_err());
+    assert!(builder
+        .build_select(
+            mask,
+            i32_type.const_zero(),
+            i32_type.const_int(1, false),
+            "bad"
+        )
+        .is_err());
+
+    // Vectors of anything other than ints are rejected before their element width is queried
+    let float_mask = context.f32_type().vec_type(4).const_zero();
+
+    assert!(builder.build_select(float_mask, lhs, rhs, "bad").is_err());
+
+    let picked = builder
+        .build_select(bool_type.const_all_ones(), lhs, rhs, "picked")
+        .unwrap();
+    let blended = builder
+        .build_select(mask, picked.into_vector_value(), rhs, "blended")
+        .unwrap();
+
+    assert_eq!(blended.get_type(), i32_vec_type.into());
+
+    builder.build_return(Some(&blended)).unwrap();
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(module.verify().is_ok());
+    assert!(
+        ir.contains("%blended = select <4 x i1> %0, <4 x i32> %picked, <4 x i32> %3"),
+        "{}",
+        ir
+    );
+}
+
+#[cfg(feature = "target-syncvm")]
+#[test]
+fn test_syncvm_intrinsic_calls() {
+    use inkwell::types::BasicTypeEnum;
+
+    let context = Context::create();
+    let module = context.create_module("syncvm");
+    let builder = context.create_builder();
+    let i256_type = context.custom_width_int_type(256);
+    let callee_type = i256_type.fn_type(&[], false);
+    let callee = module.add_function("callee", callee_type, None);
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("caller", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let catch = context.append_basic_block(fn_value, "catch");
+    let sload = module
+        .get_intrinsic_function("llvm.syncvm.sload", &[] as &[BasicTypeEnum])
+        .unwrap();
+    let sload_args: Vec<_> = sload
+        .get_type()
+        .get_param_types()
+        .into_iter()
+        .map(|param_type| param_type.const_zero())
+        .collect();
+
+    assert!(builder
+        .build_syncvm_intrinsic_call("sload", &sload_args, "value")
+        .is_err());
+
+    builder.position_at_end(entry).unwrap();
+
+    let value = builder
+        .build_syncvm_intrinsic_call("sload", &sload_args, "value")
+        .unwrap();
+
+    assert_eq!(value.get_called_fn_value(), sload);
+    assert!(builder
+        .build_syncvm_intrinsic_call("sload", &[], "value")
+        .is_err());
+    assert!(builder
+        .build_syncvm_intrinsic_call("no_such_intrinsic", &[], "value")
+        .is_err());
+
+    let abi_data = i256_type.const_zero();
+
+    // The callee takes no arguments, so it can't be near called with one
+    assert!(builder
+        .build_syncvm_near_call(callee, abi_data, &[abi_data.into()], None, "near_call")
+        .is_err());
+
+    let near_call = builder
+        .build_syncvm_near_call(callee, abi_data, &[], None, "near_call")
+        .unwrap();
+
+    assert_eq!(
+        near_call.get_called_fn_value().get_name().to_str(),
+        Ok("llvm.syncvm.nearcall")
+    );
+
+    let near_invoke = builder
+        .build_syncvm_near_call(callee, abi_data, &[], Some(catch), "near_invoke")
+        .unwrap();
+    let join = builder.get_insert_block().unwrap();
+
+    assert_eq!(
+        near_invoke.get_called_fn_value().get_name().to_str(),
+        Ok("llvm.syncvm.nearcall")
+    );
+    assert_eq!(entry.get_next_basic_block(), Some(join));
+    assert_eq!(
+        entry.get_terminator().unwrap().get_opcode(),
+        InstructionOpcode::Invoke
+    );
+}
+
+#[test]
+fn test_ensure_terminated() {
+    let context = Context::create();
+    let module = context.create_module("terminators");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let exit = context.append_basic_block(fn_value, "exit");
+
+    assert!(builder.ensure_terminated(Terminator::Unreachable).is_err());
+
+    builder.position_at_end(entry).unwrap();
+
+    let alloca = builder.build_alloca(i32_type, "slot").unwrap();
+
+    // The last instruction is no terminator, so the block is detected as unterminated
+    assert!(entry.get_last_instruction().is_some());
+    assert!(entry.get_terminator().is_none());
+
+    builder
+        .position_before(&alloca.as_instruction_value().unwrap())
+        .unwrap();
+
+    let branch = builder
+        .ensure_terminated(Terminator::Branch(exit))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(branch.get_opcode(), InstructionOpcode::Br);
+    assert_eq!(entry.get_terminator(), Some(branch));
+    assert_eq!(entry.get_last_instruction(), Some(branch));
+
+    // A second terminator is never added to an already terminated block
+    assert!(builder
+        .ensure_terminated(Terminator::Unreachable)
+        .unwrap()
+        .is_none());
+    assert_eq!(entry.get_terminator(), Some(branch));
+
+    builder.position_at_end(exit).unwrap();
+
+    // The returned value must match the function's return type
+    assert!(builder.ensure_terminated(Terminator::Return(None)).is_err());
+    assert!(builder
+        .ensure_terminated(Terminator::Return(Some(
+            context.i64_type().const_zero().into()
+        )))
+        .is_err());
+    assert!(exit.get_terminator().is_none());
+
+    let ret = builder
+        .ensure_terminated(Terminator::Return(Some(i32_type.const_zero().into())))
+        .unwrap()
+        .unwrap();
+
+    assert_eq!(ret.get_opcode(), InstructionOpcode::Return);
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_validate_terminators() {
+    let context = Context::create();
+    let module = context.create_module("terminators");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let exit = context.append_basic_block(fn_value, "exit");
+
+    builder.set_validate_terminators(true);
+    builder.position_at_end(entry).unwrap();
+
+    let alloca_ptr = builder.build_alloca(i32_type, "slot").unwrap();
+    let alloca = alloca_ptr.as_instruction_value().unwrap();
+
+    // Leaving the unterminated entry block is rejected, but moving around inside of it isn't
+    assert!(builder.position_at_end(exit).is_err());
+    assert_eq!(builder.get_insert_block(), Some(entry));
+
+    builder.position_before(&alloca).unwrap();
+    builder.position_at(entry, &alloca).unwrap();
+    builder.position_at_end(entry).unwrap();
+
+    let branch = builder.build_unconditional_branch(exit).unwrap();
+
+    // A second terminator is rejected, including one built before the first
+    assert!(builder.build_unreachable().is_err());
+    assert!(builder.build_return(None).is_err());
+    assert!(builder.build_unconditional_branch(exit).is_err());
+
+    // So is any other instruction following the terminator
+    assert!(builder.build_alloca(i32_type, "slot").is_err());
+    assert!(builder
+        .build_int_add(
+            i32_type.const_int(1, false),
+            i32_type.const_int(2, false),
+            "sum"
+        )
+        .is_err());
+    assert!(builder
+        .build_store(alloca_ptr, i32_type.const_zero())
+        .is_err());
+    assert_eq!(entry.get_last_instruction(), Some(branch));
+
+    builder.position_before(&branch).unwrap();
+
+    assert!(builder.build_return(None).is_err());
+
+    // Other instructions may still be built before the terminator
+    let store = builder
+        .build_store(alloca_ptr, i32_type.const_zero())
+        .unwrap();
+
+    assert_eq!(entry.get_terminator(), Some(branch));
+    assert_eq!(entry.get_first_instruction(), Some(alloca));
+    assert_eq!(branch.get_previous_instruction(), Some(store));
+
+    // Positioning at the end of a terminated block is rejected
+    assert!(builder.position_at_end(entry).is_err());
+
+    builder.position_at_end(exit).unwrap();
+    builder.build_return(None).unwrap();
+
+    assert!(builder
+        .ensure_terminated(Terminator::Unreachable)
+        .unwrap()
+        .is_none());
+    assert!(module.verify().is_ok());
+
+    // Nothing is checked once validation is turned off again
+    builder.set_validate_terminators(false);
+    builder.position_at_end(entry).unwrap();
+
+    assert!(builder.build_unreachable().is_ok());
+}
@@ -67,3 +67,50 @@ fn test_values_get_context() {
     assert_eq!(*i8_type.get_context(), context);
     assert_eq!(*struct_type.get_context(), context);
 }
+
+#[test]
+fn test_get_struct_type() {
+    let context = Context::create();
+
+    assert!(context.get_struct_type("Point").is_none());
+
+    {
+        let i32_type = context.i32_type();
+        let point_type = context.opaque_struct_type("Point");
+
+        point_type.set_body(&[i32_type.into(), i32_type.into()], false);
+    }
+
+    let point_type = context.get_struct_type("Point").unwrap();
+
+    assert_eq!(point_type.count_fields(), 2);
+    assert_eq!(point_type.get_name().unwrap().to_str(), Ok("Point"));
+
+    // Named structs are shared by every module in the context
+    let module = context.create_module("my_mod");
+
+    assert_eq!(module.get_struct_type("Point"), Some(point_type));
+}
+
+#[cfg(feature = "target-syncvm")]
+#[test]
+fn test_syncvm_types() {
+    use inkwell::context::{
+        SYNCVM_ADDRESS_SIZE_IN_BITS, SYNCVM_ADDRESS_SPACE_HEAP, SYNCVM_WORD_SIZE_IN_BITS,
+    };
+
+    let context = Context::create();
+    let word_type = context.syncvm_word_type();
+    let address_type = context.syncvm_address_type();
+
+    assert_eq!(word_type.get_bit_width(), 256);
+    assert_eq!(word_type.get_bit_width(), SYNCVM_WORD_SIZE_IN_BITS);
+    assert_eq!(address_type.get_bit_width(), 160);
+    assert_eq!(address_type.get_bit_width(), SYNCVM_ADDRESS_SIZE_IN_BITS);
+    assert_eq!(word_type, context.custom_width_int_type(256));
+    assert_eq!(*word_type.get_context(), context);
+
+    let heap_ptr_type = word_type.ptr_type(SYNCVM_ADDRESS_SPACE_HEAP);
+
+    assert_eq!(heap_ptr_type.get_address_space(), AddressSpace::One);
+}
@@ -72,8 +72,10 @@ fn test_smoke() {
     fn_val.set_subprogram(func_scope);
 
     let basic_block = context.append_basic_block(fn_val, "entry");
-    builder.position_at_end(basic_block);
-    builder.build_return(Some(&context.i64_type().const_zero()));
+    builder.position_at_end(basic_block).unwrap();
+    builder
+        .build_return(Some(&context.i64_type().const_zero()))
+        .unwrap();
 
     let lexical_block = dibuilder.create_lexical_block(
         func_scope.as_debug_info_scope(),
@@ -381,3 +383,116 @@ fn test_global_expressions() {
         )
     );
 }
+
+#[test]
+fn test_subroutine_type() {
+    let context = Context::create();
+    let module = context.create_module("bin");
+
+    let debug_metadata_version = context.i32_type().const_int(3, false);
+    module.add_basic_value_flag(
+        "Debug Info Version",
+        FlagBehavior::Warning,
+        debug_metadata_version,
+    );
+    let builder = context.create_builder();
+    let (dibuilder, compile_unit) = module.create_debug_info_builder(
+        true,
+        DWARFSourceLanguage::C,
+        "source_file",
+        ".",
+        "my llvm compiler frontend",
+        false,
+        "",
+        0,
+        "",
+        DWARFEmissionKind::Full,
+        0,
+        false,
+        false,
+        #[cfg(feature = "llvm11-0")]
+        "",
+        #[cfg(feature = "llvm11-0")]
+        "",
+    );
+
+    let int_type = dibuilder
+        .create_basic_type(
+            "int",
+            32_u64,
+            0x05,
+            #[cfg(not(feature = "llvm7-0"))]
+            DIFlags::PUBLIC,
+        )
+        .unwrap()
+        .as_type();
+    let add_type = dibuilder.create_subroutine_type(
+        compile_unit.get_file(),
+        Some(int_type),
+        &[int_type, int_type],
+        DIFlags::PUBLIC,
+    );
+    let noop_type =
+        dibuilder.create_subroutine_type(compile_unit.get_file(), None, &[], DIFlags::PUBLIC);
+    let add_subprogram = dibuilder.create_function(
+        compile_unit.as_debug_info_scope(),
+        "add",
+        None,
+        compile_unit.get_file(),
+        1,
+        add_type,
+        false,
+        true,
+        1,
+        DIFlags::PUBLIC,
+        false,
+    );
+    let noop_subprogram = dibuilder.create_function(
+        compile_unit.as_debug_info_scope(),
+        "noop",
+        None,
+        compile_unit.get_file(),
+        2,
+        noop_type,
+        false,
+        true,
+        2,
+        DIFlags::PUBLIC,
+        false,
+    );
+
+    let i32_type = context.i32_type();
+    let add_fn = module.add_function(
+        "add",
+        i32_type.fn_type(&[i32_type.into(), i32_type.into()], false),
+        None,
+    );
+    let noop_fn = module.add_function("noop", context.void_type().fn_type(&[], false), None);
+
+    add_fn.set_subprogram(add_subprogram);
+    noop_fn.set_subprogram(noop_subprogram);
+
+    builder
+        .position_at_end(context.append_basic_block(add_fn, "entry"))
+        .unwrap();
+
+    let lhs = add_fn.get_first_param().unwrap().into_int_value();
+    let rhs = add_fn.get_last_param().unwrap().into_int_value();
+    let sum = builder.build_int_add(lhs, rhs, "sum").unwrap();
+
+    builder.build_return(Some(&sum)).unwrap();
+    builder
+        .position_at_end(context.append_basic_block(noop_fn, "entry"))
+        .unwrap();
+    builder.build_return(None).unwrap();
+
+    dibuilder.finalize();
+
+    assert_eq!(add_fn.get_subprogram(), Some(add_subprogram));
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("DISubroutineType"), "{}", ir);
+    assert!(ir.contains("!{null}"), "{}", ir);
+}
@@ -39,8 +39,8 @@ fn test_get_function_address() {
     let fn_value = module.add_function("func", fn_type, None);
     let basic_block = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     let execution_engine = module
         .create_jit_execution_engine(OptimizationLevel::None)
@@ -82,23 +82,27 @@ fn test_jit_execution_engine() {
     main_argc.set_name("argc");
 
     // If anything goes wrong, jump to returning 1
-    builder.position_at_end(error1);
-    builder.build_return(Some(&one_i32));
+    builder.position_at_end(error1).unwrap();
+    builder.build_return(Some(&one_i32)).unwrap();
 
     // If successful, jump to returning 42
-    builder.position_at_end(success);
-    builder.build_return(Some(&fourtytwo_i32));
+    builder.position_at_end(success).unwrap();
+    builder.build_return(Some(&fourtytwo_i32)).unwrap();
 
     // See if argc == 3
-    builder.position_at_end(check_argc);
+    builder.position_at_end(check_argc).unwrap();
 
     let eq = IntPredicate::EQ;
-    let argc_check = builder.build_int_compare(eq, main_argc, three_i32, "argc_cmp");
+    let argc_check = builder
+        .build_int_compare(eq, main_argc, three_i32, "argc_cmp")
+        .unwrap();
 
-    builder.build_conditional_branch(argc_check, check_arg3, error1);
+    builder
+        .build_conditional_branch(argc_check, check_arg3, error1)
+        .unwrap();
 
-    builder.position_at_end(check_arg3);
-    builder.build_unconditional_branch(success);
+    builder.position_at_end(check_arg3).unwrap();
+    builder.build_unconditional_branch(success).unwrap();
 
     Target::initialize_native(&InitializationConfig::default())
         .expect("Failed to initialize native target");
@@ -163,6 +167,48 @@ fn test_add_remove_module() {
     assert!(ee.remove_module(&module2).is_ok());
 }
 
+#[test]
+fn test_remove_module_after_call_and_re_add() {
+    type GetAnswer = unsafe extern "C" fn() -> u32;
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let module = context.create_module("main");
+    let ee = module
+        .create_jit_execution_engine(OptimizationLevel::None)
+        .unwrap();
+
+    let answer_module = context.create_module("answer");
+    let fn_value = answer_module.add_function("get_answer", i32_type.fn_type(&[], false), None);
+
+    builder
+        .position_at_end(context.append_basic_block(fn_value, "entry"))
+        .unwrap();
+    builder
+        .build_return(Some(&i32_type.const_int(42, false)))
+        .unwrap();
+
+    assert!(ee.add_module(&answer_module).is_ok());
+
+    unsafe {
+        let get_answer = ee.get_function::<GetAnswer>("get_answer").unwrap();
+
+        assert_eq!(get_answer.call(), 42);
+    }
+
+    assert!(ee.remove_module(&answer_module).is_ok());
+    assert!(ee.remove_module(&answer_module).is_err());
+
+    // The module is usable on its own again once removed
+    assert_eq!(answer_module.get_function("get_answer"), Some(fn_value));
+    assert!(answer_module.verify().is_ok());
+
+    assert!(ee.add_module(&answer_module).is_ok());
+    assert!(ee.add_module(&answer_module).is_err());
+    assert!(ee.remove_module(&answer_module).is_ok());
+}
+
 // REVIEW: Global state pollution access tests cause this to pass when run individually
 // but fail when multiple tests are run
 // #[test]
@@ -192,8 +238,8 @@ fn test_add_remove_module() {
 //     let fn_value = module.add_function("func", fn_type, None);
 //     let basic_block = context.append_basic_block(&fn_value, "entry");
 
-//     builder.position_at_end(basic_block);
-//     builder.build_return(None);
+//     builder.position_at_end(basic_block).unwrap();
+//     builder.build_return(None).unwrap();
 
 //     assert_eq!(execution_engine.get_function_value("errors"), Err(FunctionLookupError::JITNotEnabled));
 
@@ -15,13 +15,13 @@ fn test_operands() {
     let function = module.add_function("take_f32_ptr", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let arg1 = function.get_first_param().unwrap().into_pointer_value();
     let f32_val = f32_type.const_float(::std::f64::consts::PI);
-    let store_instruction = builder.build_store(arg1, f32_val);
-    let free_instruction = builder.build_free(arg1);
-    let return_instruction = builder.build_return(None);
+    let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    let free_instruction = builder.build_free(arg1).unwrap();
+    let return_instruction = builder.build_return(None).unwrap();
 
     assert_eq!(store_instruction.get_opcode(), Store);
     assert_eq!(free_instruction.get_opcode(), Call);
@@ -156,9 +156,9 @@ fn test_basic_block_operand() {
     let basic_block = context.append_basic_block(function, "entry");
     let basic_block2 = context.append_basic_block(function, "exit");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
-    let branch_instruction = builder.build_unconditional_branch(basic_block2);
+    let branch_instruction = builder.build_unconditional_branch(basic_block2).unwrap();
     let bb_operand = branch_instruction.get_operand(0).unwrap().right().unwrap();
 
     assert_eq!(bb_operand, basic_block2);
@@ -170,12 +170,53 @@ fn test_basic_block_operand() {
         basic_block2
     );
 
-    builder.position_at_end(basic_block2);
-    builder.build_return(None);
+    builder.position_at_end(basic_block2).unwrap();
+    builder.build_return(None).unwrap();
 
     assert!(module.verify().is_ok());
 }
 
+#[test]
+fn test_switch_cases() {
+    let context = Context::create();
+    let module = context.create_module("ivs");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let fn_type = void_type.fn_type(&[i32_type.into()], false);
+    let function = module.add_function("switch", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+    let default = context.append_basic_block(function, "default");
+    let case_one = context.append_basic_block(function, "case_one");
+    let case_two = context.append_basic_block(function, "case_two");
+    let i32_one = i32_type.const_int(1, false);
+    let i32_two = i32_type.const_int(2, false);
+    let value = function.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry).unwrap();
+
+    let switch = builder.build_switch(value, default, &[]).unwrap();
+
+    assert_eq!(switch.get_switch_cases(), Ok(vec![]));
+    assert!(switch.add_case(i32_one, case_one).is_ok());
+    assert!(switch.add_case(i32_two, case_two).is_ok());
+    assert_eq!(
+        switch.get_switch_cases(),
+        Ok(vec![(i32_one, case_one), (i32_two, case_two)])
+    );
+
+    for block in &[default, case_one, case_two] {
+        builder.position_at_end(*block).unwrap();
+        builder.build_return(None).unwrap();
+    }
+
+    let ret = default.get_terminator().unwrap();
+
+    assert!(ret.add_case(i32_one, case_one).is_err());
+    assert!(ret.get_switch_cases().is_err());
+    assert!(module.verify().is_ok());
+}
+
 #[test]
 fn test_get_next_use() {
     let context = Context::create();
@@ -186,14 +227,14 @@ fn test_get_next_use() {
     let function = module.add_function("take_f32", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let arg1 = function.get_first_param().unwrap().into_float_value();
     let f32_val = f32_type.const_float(::std::f64::consts::PI);
-    let add_pi0 = builder.build_float_add(arg1, f32_val, "add_pi");
-    let add_pi1 = builder.build_float_add(add_pi0, f32_val, "add_pi");
+    let add_pi0 = builder.build_float_add(arg1, f32_val, "add_pi").unwrap();
+    let add_pi1 = builder.build_float_add(add_pi0, f32_val, "add_pi").unwrap();
 
-    builder.build_return(Some(&add_pi1));
+    builder.build_return(Some(&add_pi1)).unwrap();
 
     // f32_val constant appears twice, so there are two uses (first, next)
     let first_use = f32_val.get_first_use().unwrap();
@@ -227,7 +268,7 @@ fn test_instructions() {
     let function = module.add_function("free_f32", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let arg1 = function.get_first_param().unwrap().into_pointer_value();
     let arg2 = function.get_nth_param(1).unwrap().into_float_value();
@@ -237,14 +278,20 @@ fn test_instructions() {
 
     let f32_val = f32_type.const_float(::std::f64::consts::PI);
 
-    let store_instruction = builder.build_store(arg1, f32_val);
-    let ptr_val = builder.build_ptr_to_int(arg1, i64_type, "ptr_val");
-    let ptr = builder.build_int_to_ptr(ptr_val, f32_ptr_type, "ptr");
-    let icmp = builder.build_int_compare(IntPredicate::EQ, ptr_val, ptr_val, "icmp");
-    let f32_sum = builder.build_float_add(arg2, f32_val, "f32_sum");
-    let fcmp = builder.build_float_compare(FloatPredicate::OEQ, f32_sum, arg2, "fcmp");
-    let free_instruction = builder.build_free(arg1);
-    let return_instruction = builder.build_return(None);
+    let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    let ptr_val = builder.build_ptr_to_int(arg1, i64_type, "ptr_val").unwrap();
+    let ptr = builder
+        .build_int_to_ptr(ptr_val, f32_ptr_type, "ptr")
+        .unwrap();
+    let icmp = builder
+        .build_int_compare(IntPredicate::EQ, ptr_val, ptr_val, "icmp")
+        .unwrap();
+    let f32_sum = builder.build_float_add(arg2, f32_val, "f32_sum").unwrap();
+    let fcmp = builder
+        .build_float_compare(FloatPredicate::OEQ, f32_sum, arg2, "fcmp")
+        .unwrap();
+    let free_instruction = builder.build_free(arg1).unwrap();
+    let return_instruction = builder.build_return(None).unwrap();
 
     assert_eq!(store_instruction.get_opcode(), Store);
     assert_eq!(ptr_val.as_instruction().unwrap().get_opcode(), PtrToInt);
@@ -296,7 +343,7 @@ fn test_volatile_atomicrmw_cmpxchg() {
     let function = module.add_function("mem_inst", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let arg1 = function.get_first_param().unwrap().into_pointer_value();
     let arg2 = function.get_nth_param(1).unwrap().into_int_value();
@@ -350,7 +397,7 @@ fn test_mem_instructions() {
     let function = module.add_function("mem_inst", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let arg1 = function.get_first_param().unwrap().into_pointer_value();
     let arg2 = function.get_nth_param(1).unwrap().into_float_value();
@@ -360,8 +407,8 @@ fn test_mem_instructions() {
 
     let f32_val = f32_type.const_float(::std::f64::consts::PI);
 
-    let store_instruction = builder.build_store(arg1, f32_val);
-    let load = builder.build_load(arg1, "");
+    let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    let load = builder.build_load(arg1, "").unwrap();
     let load_instruction = load.as_instruction_value().unwrap();
 
     assert_eq!(store_instruction.get_volatile().unwrap(), false);
@@ -391,6 +438,7 @@ fn test_mem_instructions() {
 
     let fadd_instruction = builder
         .build_float_add(load.into_float_value(), f32_val, "")
+        .unwrap()
         .as_instruction_value()
         .unwrap();
     assert!(fadd_instruction.get_volatile().is_err());
@@ -414,7 +462,7 @@ fn test_mem_instructions() {
     let function = module.add_function("mem_inst", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let arg1 = function.get_first_param().unwrap().into_pointer_value();
     let arg2 = function.get_nth_param(1).unwrap().into_float_value();
@@ -424,8 +472,8 @@ fn test_mem_instructions() {
 
     let f32_val = f32_type.const_float(::std::f64::consts::PI);
 
-    let store_instruction = builder.build_store(arg1, f32_val);
-    let load = builder.build_load(arg1, "");
+    let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    let load = builder.build_load(arg1, "").unwrap();
     let load_instruction = load.as_instruction_value().unwrap();
 
     assert_eq!(store_instruction.get_volatile().unwrap(), false);
@@ -455,6 +503,7 @@ fn test_mem_instructions() {
 
     let fadd_instruction = builder
         .build_float_add(load.into_float_value(), f32_val, "")
+        .unwrap()
         .as_instruction_value()
         .unwrap();
     assert!(fadd_instruction.get_volatile().is_err());
@@ -478,7 +527,7 @@ fn test_atomic_ordering_mem_instructions() {
     let function = module.add_function("mem_inst", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let arg1 = function.get_first_param().unwrap().into_pointer_value();
     let arg2 = function.get_nth_param(1).unwrap().into_float_value();
@@ -488,8 +537,8 @@ fn test_atomic_ordering_mem_instructions() {
 
     let f32_val = f32_type.const_float(::std::f64::consts::PI);
 
-    let store_instruction = builder.build_store(arg1, f32_val);
-    let load = builder.build_load(arg1, "");
+    let store_instruction = builder.build_store(arg1, f32_val).unwrap();
+    let load = builder.build_load(arg1, "").unwrap();
     let load_instruction = load.as_instruction_value().unwrap();
 
     assert_eq!(
@@ -529,6 +578,7 @@ fn test_atomic_ordering_mem_instructions() {
 
     let fadd_instruction = builder
         .build_float_add(load.into_float_value(), f32_val, "")
+        .unwrap()
         .as_instruction_value()
         .unwrap();
     assert!(fadd_instruction.get_atomic_ordering().is_err());
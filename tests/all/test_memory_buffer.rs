@@ -0,0 +1,56 @@
+extern crate inkwell;
+
+use self::inkwell::memory_buffer::MemoryBuffer;
+
+#[test]
+fn test_null_terminated_buffer() {
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(b"my data", "my_buffer");
+
+    assert!(memory_buffer.is_null_terminated());
+    assert_eq!(memory_buffer.get_size(), 7);
+    assert_eq!(memory_buffer.as_slice(), b"my data");
+    assert_eq!(memory_buffer.as_c_slice(), b"my data\0");
+}
+
+#[test]
+fn test_non_null_terminated_buffer() {
+    let memory_buffer = MemoryBuffer::create_from_memory_range(b"my data", "my_buffer");
+
+    assert!(!memory_buffer.is_null_terminated());
+    assert_eq!(memory_buffer.get_size(), 7);
+    assert_eq!(memory_buffer.as_slice(), b"my data");
+    assert_eq!(memory_buffer.as_c_slice(), b"my data");
+}
+
+#[test]
+fn test_to_vec_outlives_buffer() {
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(b"my data", "my_buffer");
+    let bytes = memory_buffer.to_vec();
+
+    drop(memory_buffer);
+
+    assert_eq!(bytes, b"my data");
+    assert_eq!(
+        MemoryBuffer::create_from_memory_range(b"", "empty_buffer").to_vec(),
+        Vec::<u8>::new()
+    );
+}
+
+#[test]
+fn test_content_eq_and_first_difference() {
+    let expected = MemoryBuffer::create_from_memory_range_copy(b"golden bytecode", "expected");
+    let same = MemoryBuffer::create_from_memory_range_copy(b"golden bytecode", "same");
+    let changed = MemoryBuffer::create_from_memory_range_copy(b"golden bitcode!", "changed");
+    let truncated = MemoryBuffer::create_from_memory_range_copy(b"golden", "truncated");
+
+    assert!(expected.content_eq(&same));
+    assert_eq!(expected.first_difference(&same), None);
+
+    assert!(!expected.content_eq(&changed));
+    assert_eq!(expected.first_difference(&changed), Some(8));
+    assert_eq!(changed.first_difference(&expected), Some(8));
+
+    assert!(!expected.content_eq(&truncated));
+    assert_eq!(expected.first_difference(&truncated), Some(6));
+    assert_eq!(truncated.first_difference(&expected), Some(6));
+}
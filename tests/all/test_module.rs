@@ -2,10 +2,12 @@ extern crate inkwell;
 
 use self::inkwell::context::Context;
 use self::inkwell::memory_buffer::MemoryBuffer;
-use self::inkwell::module::Module;
+use self::inkwell::module::{FunctionTypeMismatch, Linkage, Module, VerifierFailureAction};
+use self::inkwell::passes::PassManager;
 use self::inkwell::targets::{Target, TargetTriple};
+use self::inkwell::types::IntType;
 use self::inkwell::values::AnyValue;
-use self::inkwell::OptimizationLevel;
+use self::inkwell::{AddressSpace, OptimizationLevel};
 
 use std::env::temp_dir;
 use std::fs::{remove_file, File};
@@ -109,8 +111,8 @@ fn test_write_and_load_memory_buffer() {
     let function = module.add_function("my_fn", function_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     let memory_buffer = module.write_bitcode_to_memory();
 
@@ -216,8 +218,8 @@ fn test_parse_from_buffer() {
     let basic_block = context.append_basic_block(f, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     assert!(module.verify().is_ok());
 
@@ -257,8 +259,8 @@ fn test_parse_from_path() {
     let basic_block = context.append_basic_block(f, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     assert!(module.verify().is_ok(), "3");
 
@@ -285,8 +287,8 @@ fn test_clone() {
     let basic_block = context.append_basic_block(f, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     let module2 = module.clone();
 
@@ -294,6 +296,41 @@ fn test_clone() {
     assert_eq!(module.print_to_string(), module2.print_to_string());
 }
 
+#[test]
+fn test_clone_is_independent() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let f = module.add_function("f", fn_type, None);
+    let basic_block = context.append_basic_block(f, "entry");
+
+    builder.position_at_end(basic_block).unwrap();
+
+    let alloca = builder.build_alloca(i32_type, "local").unwrap();
+
+    builder
+        .build_store(alloca, i32_type.const_int(42, false))
+        .unwrap();
+
+    let load = builder.build_load(alloca, "load").unwrap();
+
+    builder.build_return(Some(&load)).unwrap();
+
+    let module2 = module.clone();
+    let pass_manager = PassManager::create(());
+
+    pass_manager.add_promote_memory_to_register_pass();
+
+    assert!(pass_manager.run_on(&module2));
+    assert_eq!(*module2.get_context(), context);
+    assert!(!module2.print_to_string().to_string().contains("alloca"));
+    assert!(module.print_to_string().to_string().contains("alloca"));
+    assert!(module.verify().is_ok());
+    assert!(module2.verify().is_ok());
+}
+
 #[test]
 fn test_print_to_file() {
     let context = Context::create();
@@ -304,8 +341,8 @@ fn test_print_to_file() {
     let basic_block = context.append_basic_block(f, "entry");
     let builder = context.create_builder();
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     let bad_path = Path::new("/tmp/some/silly/path/that/sure/doesn't/exist");
 
@@ -378,8 +415,8 @@ fn test_linking_modules() {
     let fn_val = module.add_function("f", fn_type, None);
     let basic_block = context.append_basic_block(fn_val, "entry");
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     let module2 = context.create_module("mod2");
 
@@ -392,8 +429,8 @@ fn test_linking_modules() {
     let fn_val2 = module3.add_function("f2", fn_type, None);
     let basic_block2 = context.append_basic_block(fn_val2, "entry");
 
-    builder.position_at_end(basic_block2);
-    builder.build_return(None);
+    builder.position_at_end(basic_block2).unwrap();
+    builder.build_return(None).unwrap();
 
     // Unowned module links in unowned module
     assert!(module.link_in_module(module3).is_ok());
@@ -414,8 +451,8 @@ fn test_linking_modules() {
     let fn_val3 = module5.add_function("f2", fn_type, None);
     let basic_block3 = context.append_basic_block(fn_val3, "entry");
 
-    builder.position_at_end(basic_block3);
-    builder.build_return(None);
+    builder.position_at_end(basic_block3).unwrap();
+    builder.build_return(None).unwrap();
 
     // EE owned module links in unowned module which has
     // another definition for the same funciton name, "f2"
@@ -431,8 +468,8 @@ fn test_linking_modules() {
     let fn_val4 = module6.add_function("f4", fn_type, None);
     let basic_block4 = context.append_basic_block(fn_val4, "entry");
 
-    builder.position_at_end(basic_block4);
-    builder.build_return(None);
+    builder.position_at_end(basic_block4).unwrap();
+    builder.build_return(None).unwrap();
 
     let execution_engine2 = module6
         .create_jit_execution_engine(OptimizationLevel::None)
@@ -489,6 +526,206 @@ fn test_metadata_flags() {
     }
 }
 
+#[test]
+fn test_named_metadata_operands() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let md_string = context.metadata_string("my_kernel");
+    let md_node = context.metadata_node(&[md_string.into(), i32_type.const_int(1, false).into()]);
+
+    assert!(module.get_global_metadata("my.kernels").is_empty());
+
+    module.add_global_metadata("my.kernels", &md_node);
+
+    let operands = module.get_global_metadata("my.kernels");
+
+    assert_eq!(operands, vec![md_node]);
+    assert_eq!(operands[0].get_node_size(), 2);
+    assert_eq!(
+        operands[0].get_node_values()[0]
+            .into_metadata_value()
+            .get_string_value(),
+        md_string.get_string_value()
+    );
+    assert!(module.get_global_metadata("other.kernels").is_empty());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_set_inline_assembly() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    module.add_function("my_fn", context.void_type().fn_type(&[], false), None);
+    module.set_inline_assembly(".globl foo");
+    module.set_inline_assembly(".globl bar");
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("module asm \".globl bar\""), "{}", ir);
+    assert!(!ir.contains(".globl foo"), "{}", ir);
+    assert!(
+        ir.find("module asm").unwrap() < ir.find("declare void @my_fn()").unwrap(),
+        "{}",
+        ir
+    );
+}
+
+#[llvm_versions(7.0..=latest)]
+#[test]
+fn test_append_inline_assembly() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    module.set_inline_assembly(".globl foo");
+    module.append_inline_assembly(".globl bar");
+    module.append_inline_assembly(".globl baz");
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(
+        ir.contains(
+            "module asm \".globl foo\"\nmodule asm \".globl bar\"\nmodule asm \".globl baz\""
+        ),
+        "{}",
+        ir
+    );
+}
+
+#[llvm_versions(9.0..=latest)]
+#[test]
+fn test_global_ifunc() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let impl_type = i32_type.fn_type(&[], false);
+    let impl_ptr_type = impl_type.ptr_type(AddressSpace::Zero);
+    let resolver_type = impl_ptr_type.fn_type(&[], false);
+
+    let use_fast_impl = module.add_global(context.bool_type(), None, "use_fast_impl");
+
+    use_fast_impl
+        .set_initializer(&context.bool_type().const_zero())
+        .unwrap();
+
+    let mut impls = Vec::new();
+
+    for (name, value) in &[("slow_impl", 1), ("fast_impl", 2)] {
+        let impl_fn = module.add_function(name, impl_type, None);
+
+        builder
+            .position_at_end(context.append_basic_block(impl_fn, "entry"))
+            .unwrap();
+        builder
+            .build_return(Some(&i32_type.const_int(*value, false)))
+            .unwrap();
+        impls.push(impl_fn);
+    }
+
+    let resolver = module.add_function("resolve_my_fn", resolver_type, None);
+
+    builder
+        .position_at_end(context.append_basic_block(resolver, "entry"))
+        .unwrap();
+
+    let use_fast_impl = builder
+        .build_load(use_fast_impl.as_pointer_value(), "use_fast_impl")
+        .unwrap()
+        .into_int_value();
+    let picked = builder
+        .build_select(
+            use_fast_impl,
+            impls[1].as_global_value().as_pointer_value(),
+            impls[0].as_global_value().as_pointer_value(),
+            "picked",
+        )
+        .unwrap();
+
+    builder.build_return(Some(&picked)).unwrap();
+
+    let ifunc = module.add_global_ifunc("my_fn", impl_type, AddressSpace::Zero, resolver);
+
+    assert_eq!(ifunc.get_name().to_str(), Ok("my_fn"));
+    assert_eq!(ifunc.get_resolver(), Some(resolver));
+
+    let caller = module.add_function("caller", impl_type, None);
+
+    builder
+        .position_at_end(context.append_basic_block(caller, "entry"))
+        .unwrap();
+
+    let result = builder
+        .build_call(ifunc.as_pointer_value(), &[], "result")
+        .unwrap()
+        .try_as_basic_value()
+        .left()
+        .unwrap();
+
+    builder.build_return(Some(&result)).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(
+        ir.contains("@my_fn = ifunc i32 (), i32 ()* ()* @resolve_my_fn"),
+        "{}",
+        ir
+    );
+
+    let other_resolver = module.add_function("resolve_my_fn_again", resolver_type, None);
+
+    ifunc.set_resolver(other_resolver);
+
+    assert_eq!(ifunc.get_resolver(), Some(other_resolver));
+}
+
+#[test]
+fn test_get_or_add_function() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let other_fn_type = i32_type.fn_type(&[], false);
+
+    assert!(module.get_function("my_fn").is_none());
+
+    let fn_value = module
+        .get_or_add_function("my_fn", fn_type, Some(Linkage::Internal))
+        .unwrap();
+
+    assert_eq!(module.get_function("my_fn"), Some(fn_value));
+    assert_eq!(fn_value.get_type(), fn_type);
+    assert_eq!(fn_value.get_linkage(), Linkage::Internal);
+
+    let fetched = module
+        .get_or_add_function("my_fn", fn_type, Some(Linkage::External))
+        .unwrap();
+
+    assert_eq!(fetched, fn_value);
+    assert_eq!(fetched.get_linkage(), Linkage::Internal);
+    assert_eq!(module.get_first_function(), module.get_last_function());
+
+    let mismatch = module
+        .get_or_add_function("my_fn", other_fn_type, None)
+        .unwrap_err();
+
+    assert_eq!(
+        mismatch,
+        FunctionTypeMismatch {
+            expected: other_fn_type,
+            found: fn_type,
+        }
+    );
+    assert_eq!(
+        mismatch.to_string(),
+        "FunctionTypeMismatch(expected i32 (), found i32 (i32))"
+    );
+    assert_eq!(module.get_first_function(), module.get_last_function());
+}
+
 #[test]
 fn test_double_ee_from_same_module() {
     let context = Context::create();
@@ -499,8 +736,8 @@ fn test_double_ee_from_same_module() {
     let fn_val = module.add_function("f", fn_type, None);
     let basic_block = context.append_basic_block(fn_val, "entry");
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     module
         .create_execution_engine()
@@ -526,3 +763,113 @@ fn test_double_ee_from_same_module() {
 
     assert!(module.create_interpreter_execution_engine().is_err());
 }
+
+#[test]
+fn test_verify_message() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("broken", fn_type, None);
+    let entry = context.append_basic_block(function, "entry");
+
+    assert!(module.verify().is_ok());
+    assert!(module
+        .verify_with_action(VerifierFailureAction::ReturnStatus)
+        .is_ok());
+
+    builder.position_at_end(entry).unwrap();
+    builder
+        .build_return(Some(&i32_type.const_int(1, false)))
+        .unwrap();
+
+    let err = module.verify().unwrap_err();
+    let message = err.to_string();
+
+    assert!(message.contains("ret i32 1"), "{}", message);
+
+    let err = module
+        .verify_with_action(VerifierFailureAction::PrintMessage)
+        .unwrap_err();
+
+    assert_eq!(err.to_string(), message);
+}
+
+#[llvm_versions(3.9..=latest)]
+#[test]
+fn test_parse_bitcode_with_diagnostics() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry).unwrap();
+    builder.build_return(None).unwrap();
+
+    let buffer = module.write_bitcode_to_memory();
+    let (module2, warnings) =
+        Module::parse_bitcode_from_buffer_with_diagnostics(&buffer, &context).unwrap();
+
+    assert!(warnings.is_empty(), "{:?}", warnings);
+    assert!(module2.get_function("my_fn").is_some());
+
+    let garbage_buffer = MemoryBuffer::create_from_memory_range(b"garbage ir data", "my_ir");
+
+    assert!(Module::parse_bitcode_from_buffer_with_diagnostics(&garbage_buffer, &context).is_err());
+}
+
+#[llvm_versions(3.9..=latest)]
+#[test]
+fn test_parse_bitcode_with_diagnostics_restores_handler() {
+    let context = Context::create();
+    let module = context.create_module("stale_debug_info");
+    let i32_type = context.i32_type();
+    let md_node = context.metadata_node(&[i32_type.const_int(0, false).into()]);
+
+    // Debug info without a "Debug Info Version" module flag gets stripped when it's read back
+    module.add_global_metadata("llvm.dbg.cu", &md_node);
+
+    let buffer = module.write_bitcode_to_memory();
+
+    for _ in 0..2 {
+        let (module2, warnings) =
+            Module::parse_bitcode_from_buffer_with_diagnostics(&buffer, &context).unwrap();
+
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert!(warnings[0].contains("invalid version"), "{:?}", warnings);
+        assert!(module2.get_global_metadata("llvm.dbg.cu").is_empty());
+    }
+
+    // The collecting handler must be gone by now, or this would push the
+    // warning into the freed vec of the last call
+    assert!(Module::parse_bitcode_from_buffer(&buffer, &context).is_ok());
+}
+
+#[llvm_versions(8.0..=latest)]
+#[test]
+fn test_get_intrinsic_function() {
+    let context = Context::create();
+    let module = context.create_module("intrinsics");
+    let i32_type = context.i32_type();
+
+    assert!(module
+        .get_intrinsic_function("llvm.not.an.intrinsic", &[i32_type])
+        .is_none());
+
+    // Overloaded intrinsics can only be declared for the types they are overloaded for
+    assert!(module
+        .get_intrinsic_function("llvm.sadd.sat", &[] as &[IntType])
+        .is_none());
+
+    let sadd_sat = module
+        .get_intrinsic_function("llvm.sadd.sat", &[i32_type])
+        .unwrap();
+
+    assert_eq!(sadd_sat.get_name().to_str(), Ok("llvm.sadd.sat.i32"));
+    assert_eq!(module.get_function("llvm.sadd.sat.i32"), Some(sadd_sat));
+}
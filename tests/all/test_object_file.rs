@@ -76,15 +76,18 @@ fn test_section_iterator() {
     let mut module = context.create_module("test_section_iterator");
 
     let gv_a = module.add_global(context.i8_type(), None, "a");
-    gv_a.set_initializer(&context.i8_type().const_zero().as_basic_value_enum());
+    gv_a.set_initializer(&context.i8_type().const_zero().as_basic_value_enum())
+        .unwrap();
     gv_a.set_section("A");
 
     let gv_b = module.add_global(context.i16_type(), None, "b");
-    gv_b.set_initializer(&context.i16_type().const_zero().as_basic_value_enum());
+    gv_b.set_initializer(&context.i16_type().const_zero().as_basic_value_enum())
+        .unwrap();
     gv_b.set_section("B");
 
     let gv_c = module.add_global(context.i32_type(), None, "c");
-    gv_c.set_initializer(&context.i32_type().const_zero().as_basic_value_enum());
+    gv_c.set_initializer(&context.i32_type().const_zero().as_basic_value_enum())
+        .unwrap();
     gv_c.set_section("C");
 
     apply_target_to_module(&target_machine, &module);
@@ -132,13 +135,16 @@ fn test_symbol_iterator() {
     let mut module = context.create_module("test_symbol_iterator");
     module
         .add_global(context.i8_type(), None, "a")
-        .set_initializer(&context.i8_type().const_zero().as_basic_value_enum());
+        .set_initializer(&context.i8_type().const_zero().as_basic_value_enum())
+        .unwrap();
     module
         .add_global(context.i16_type(), None, "b")
-        .set_initializer(&context.i16_type().const_zero().as_basic_value_enum());
+        .set_initializer(&context.i16_type().const_zero().as_basic_value_enum())
+        .unwrap();
     module
         .add_global(context.i32_type(), None, "c")
-        .set_initializer(&context.i32_type().const_zero().as_basic_value_enum());
+        .set_initializer(&context.i32_type().const_zero().as_basic_value_enum())
+        .unwrap();
     apply_target_to_module(&target_machine, &module);
 
     let memory_buffer = target_machine
@@ -176,6 +182,32 @@ fn test_symbol_iterator() {
     assert!(has_symbol_c);
 }
 
+#[test]
+fn test_get_symbol_by_name() {
+    let target_machine = get_native_target_machine();
+
+    let context = Context::create();
+    let mut module = context.create_module("test_get_symbol_by_name");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let function = module.add_function("my_function", fn_type, None);
+    let builder = context.create_builder();
+    builder
+        .position_at_end(context.append_basic_block(function, "entry"))
+        .unwrap();
+    builder.build_return(None).unwrap();
+    apply_target_to_module(&target_machine, &module);
+
+    let memory_buffer = target_machine
+        .write_to_memory_buffer(&mut module, FileType::Object)
+        .unwrap();
+    let object_file = memory_buffer.create_object_file().unwrap();
+
+    let symbol = object_file.get_symbol_by_name("my_function").unwrap();
+
+    assert_eq!(symbol.get_name().unwrap().to_str(), Ok("my_function"));
+    assert!(object_file.get_symbol_by_name("missing_function").is_none());
+}
+
 #[test]
 fn test_reloc_iterator() {
     let target_machine = get_native_target_machine();
@@ -192,7 +224,8 @@ fn test_reloc_iterator() {
         .const_add(intptr_t.const_int(4, false));
     module
         .add_global(intptr_t, None, "a")
-        .set_initializer(&x_plus_4);
+        .set_initializer(&x_plus_4)
+        .unwrap();
 
     apply_target_to_module(&target_machine, &module);
 
@@ -224,7 +257,8 @@ fn test_section_contains_nul() {
             .i32_type()
             .const_int(0xff0000ff, false)
             .as_basic_value_enum(),
-    );
+    )
+    .unwrap();
     gv.set_section("test");
 
     apply_target_to_module(&target_machine, &module);
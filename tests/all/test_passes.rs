@@ -143,8 +143,8 @@ fn test_pass_manager_builder() {
     let builder = context.create_builder();
     let entry = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry);
-    builder.build_return(None);
+    builder.position_at_end(entry).unwrap();
+    builder.build_return(None).unwrap();
 
     #[cfg(not(feature = "llvm3-7"))]
     assert!(!fn_pass_manager.initialize());
@@ -433,6 +433,40 @@ fn test_write_target_machine_to_file() {
     remove_file(&path).unwrap();
 }
 
+#[test]
+fn test_target_machine_reloc_mode_and_code_model() {
+    Target::initialize_x86(&InitializationConfig::default());
+
+    let target = Target::from_name("x86-64").unwrap();
+    let target_machine = target
+        .create_target_machine(
+            &TargetTriple::create("x86_64-pc-linux-gnu"),
+            "x86-64",
+            "+avx2",
+            OptimizationLevel::Aggressive,
+            RelocMode::PIC,
+            CodeModel::Medium,
+        )
+        .unwrap();
+
+    assert_eq!(target_machine.get_reloc_mode(), RelocMode::PIC);
+    assert_eq!(target_machine.get_code_model(), CodeModel::Medium);
+
+    let static_target_machine = target
+        .create_target_machine(
+            &TargetTriple::create("x86_64-pc-linux-gnu"),
+            "x86-64",
+            "",
+            OptimizationLevel::None,
+            RelocMode::Static,
+            CodeModel::Default,
+        )
+        .unwrap();
+
+    assert_eq!(static_target_machine.get_reloc_mode(), RelocMode::Static);
+    assert_eq!(static_target_machine.get_code_model(), CodeModel::Default);
+}
+
 #[test]
 fn test_write_target_machine_to_memory_buffer() {
     Target::initialize_x86(&InitializationConfig::default());
@@ -469,3 +503,27 @@ fn test_write_target_machine_to_memory_buffer() {
     assert!(string.contains("my_module"));
     assert!(string.contains(".section"));
 }
+
+#[test]
+fn test_data_layout_queries() {
+    let context = Context::create();
+    let module = context.create_module("layout");
+    let i64_type = context.i64_type();
+
+    module.set_data_layout(&TargetData::create("E-p:32:32-p1:64:64-i64:32").get_data_layout());
+
+    let data_layout = module.get_data_layout();
+
+    assert!(!data_layout.is_little_endian());
+    assert_eq!(data_layout.get_pointer_size(None), 4);
+    assert_eq!(data_layout.get_pointer_size(Some(AddressSpace::One)), 8);
+    assert_eq!(data_layout.get_abi_alignment(&i64_type), 4);
+    assert_eq!(data_layout.to_target_data().get_data_layout(), *data_layout);
+
+    module.set_data_layout(&TargetData::create("e-p:64:64").get_data_layout());
+
+    let data_layout = module.get_data_layout();
+
+    assert!(data_layout.is_little_endian());
+    assert_eq!(data_layout.get_pointer_size(None), 8);
+}
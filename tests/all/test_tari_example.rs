@@ -23,16 +23,16 @@ fn test_tari_example() {
     let function = module.add_function("sum", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let x = function.get_nth_param(0).unwrap().into_int_value();
     let y = function.get_nth_param(1).unwrap().into_int_value();
     let z = function.get_nth_param(2).unwrap().into_int_value();
 
-    let sum = builder.build_int_add(x, y, "sum");
-    let sum = builder.build_int_add(sum, z, "sum");
+    let sum = builder.build_int_add(x, y, "sum").unwrap();
+    let sum = builder.build_int_add(sum, z, "sum").unwrap();
 
-    builder.build_return(Some(&sum));
+    builder.build_return(Some(&sum)).unwrap();
 
     unsafe {
         type Sum = unsafe extern "C" fn(u64, u64, u64) -> u64;
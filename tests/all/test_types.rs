@@ -1,5 +1,5 @@
 use inkwell::context::Context;
-use inkwell::types::BasicType;
+use inkwell::types::{AnyType, BasicType, FunctionType};
 use inkwell::values::AnyValue;
 use inkwell::AddressSpace;
 
@@ -137,6 +137,49 @@ fn test_function_type() {
     assert_eq!(*fn_type.get_context(), context);
 }
 
+#[test]
+fn test_function_type_param_types_iter() {
+    let context = Context::create();
+    let i8_type = context.i8_type();
+    let i64_type = context.i64_type();
+    let f32_type = context.f32_type();
+    let ptr_type = i8_type.ptr_type(AddressSpace::Zero);
+    let struct_type = context.struct_type(&[i8_type.into(), f32_type.into()], false);
+    let fn_type = i8_type.fn_type(
+        &[
+            i8_type.into(),
+            i64_type.into(),
+            f32_type.into(),
+            ptr_type.into(),
+            struct_type.into(),
+        ],
+        true,
+    );
+
+    assert!(fn_type.is_var_arg());
+    assert_eq!(fn_type.count_param_types(), 5);
+    assert_eq!(fn_type.get_return_type().unwrap().into_int_type(), i8_type);
+
+    let mut param_types_iter = fn_type.get_param_types_iter();
+
+    assert_eq!(param_types_iter.len(), 5);
+    assert_eq!(param_types_iter.next().unwrap().into_int_type(), i8_type);
+    assert_eq!(param_types_iter.next().unwrap().into_int_type(), i64_type);
+    assert_eq!(param_types_iter.next().unwrap().into_float_type(), f32_type);
+    assert_eq!(
+        param_types_iter.next().unwrap().into_pointer_type(),
+        ptr_type
+    );
+    assert_eq!(
+        param_types_iter.next().unwrap().into_struct_type(),
+        struct_type
+    );
+    assert!(param_types_iter.next().is_none());
+    assert!(fn_type
+        .get_param_types_iter()
+        .eq(fn_type.get_param_types().into_iter()));
+}
+
 #[test]
 fn test_sized_types() {
     unsafe { Context::get_global(sized_types) }
@@ -243,6 +286,55 @@ fn sized_types(global_ctx: &Context) {
     assert!(!opaque_struct_type.array_type(0).is_sized());
 }
 
+#[test]
+fn test_size_and_alignment_of_unsized_types() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let sized_struct_type = context.struct_type(&[i32_type.into(), i32_type.into()], false);
+    let opaque_struct_type = context.opaque_struct_type("opaque");
+
+    assert!(sized_struct_type.as_basic_type_enum().is_sized());
+    assert!(sized_struct_type.size_of().is_some());
+    assert!(sized_struct_type.get_alignment().is_some());
+    assert!(sized_struct_type.array_type(2).get_alignment().is_some());
+
+    assert!(!opaque_struct_type.as_basic_type_enum().is_sized());
+    assert!(opaque_struct_type.size_of().is_none());
+    assert!(opaque_struct_type.get_alignment().is_none());
+    assert!(opaque_struct_type.as_basic_type_enum().size_of().is_none());
+    assert!(opaque_struct_type.array_type(2).size_of().is_none());
+    assert!(opaque_struct_type.array_type(2).get_alignment().is_none());
+
+    opaque_struct_type.set_body(&[i32_type.into()], false);
+
+    assert!(opaque_struct_type.size_of().is_some());
+    assert!(opaque_struct_type.get_alignment().is_some());
+}
+
+#[test]
+fn test_is_void_type() {
+    let context = Context::create();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+
+    assert_eq!(void_type, context.void_type());
+    assert!(void_type.as_any_type_enum().is_void_type());
+    assert!(!i32_type.as_any_type_enum().is_void_type());
+
+    let void_fn_type = void_type.fn_type(&[], false);
+    let i32_fn_type = i32_type.fn_type(&[], false);
+    let return_type = |fn_type: FunctionType| {
+        fn_type
+            .get_return_type()
+            .map_or(context.void_type().as_any_type_enum(), |ty| {
+                ty.as_any_type_enum()
+            })
+    };
+
+    assert!(return_type(void_fn_type).is_void_type());
+    assert!(!return_type(i32_fn_type).is_void_type());
+}
+
 #[test]
 fn test_const_zero() {
     let context = Context::create();
@@ -2,10 +2,12 @@ use inkwell::attributes::AttributeLoc;
 #[llvm_versions(7.0..=latest)]
 use inkwell::comdat::ComdatSelectionKind;
 use inkwell::context::Context;
-use inkwell::module::Linkage::*;
+use inkwell::module::{Linkage::*, Module};
 use inkwell::types::{AnyType, StringRadix, VectorType};
 use inkwell::values::{AnyValue, BasicValue, InstructionOpcode::*, FIRST_CUSTOM_METADATA_KIND_ID};
-use inkwell::{AddressSpace, DLLStorageClass, FloatPredicate, GlobalVisibility, ThreadLocalMode};
+use inkwell::{
+    AddressSpace, CallConv, DLLStorageClass, FloatPredicate, GlobalVisibility, ThreadLocalMode,
+};
 
 use std::convert::TryFrom;
 
@@ -39,7 +41,9 @@ fn test_call_site() {
 
     let function = module.add_function("do_nothing", fn_type, None);
 
-    let call_site = builder.build_call(function, &[], "to_infinity_and_beyond");
+    let call_site = builder
+        .build_call(function, &[], "to_infinity_and_beyond")
+        .unwrap();
 
     #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
     assert_eq!(call_site.count_arguments(), 0);
@@ -165,7 +169,7 @@ fn test_set_get_name() {
     let function = module.add_function("do_stuff", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let int_param = function.get_nth_param(0).unwrap().into_int_value();
     let float_param = function.get_nth_param(1).unwrap().into_float_value();
@@ -173,7 +177,7 @@ fn test_set_get_name() {
     let array_param = function.get_nth_param(3).unwrap().into_array_value();
     let ptr_param = function.get_nth_param(4).unwrap().into_pointer_value();
     let vec_param = function.get_nth_param(5).unwrap().into_vector_value();
-    let phi_val = builder.build_phi(bool_type, "phi_node");
+    let phi_val = builder.build_phi(bool_type, "phi_node").unwrap();
 
     assert_eq!(int_param.get_name().to_str(), Ok(""));
     assert_eq!(float_param.get_name().to_str(), Ok(""));
@@ -348,14 +352,63 @@ fn test_verify_fn() {
 
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
-    builder.build_return(None);
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
 
     assert!(function.verify(false));
 
     // TODO: Verify other verify modes
 }
 
+#[test]
+fn test_verify_fn_with_message() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("fns");
+
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    let function = module.add_function("no_terminator", fn_type, None);
+    let basic_block = context.append_basic_block(function, "entry");
+
+    let err = function.verify_with_message().unwrap_err();
+
+    assert!(err.contains("does not have terminator"), "{}", err);
+    assert!(err.contains("no_terminator"), "{}", err);
+
+    // Problems of other functions don't make a valid function fail
+    let half_built = module.add_function("half_built", fn_type, None);
+
+    context.append_basic_block(half_built, "entry");
+
+    let err = function.verify_with_message().unwrap_err();
+
+    assert!(err.contains("no_terminator"), "{}", err);
+
+    builder.position_at_end(basic_block).unwrap();
+    builder.build_return(None).unwrap();
+
+    assert_eq!(function.verify_with_message(), Ok(()));
+    assert!(half_built
+        .verify_with_message()
+        .unwrap_err()
+        .contains("half_built"));
+
+    // Diagnostics which don't name the function are kept along with the offending instruction
+    let half_built_block = half_built.get_first_basic_block().unwrap();
+
+    builder.position_at_end(half_built_block).unwrap();
+    builder
+        .build_return(Some(&context.i32_type().const_int(1, false)))
+        .unwrap();
+
+    let err = half_built.verify_with_message().unwrap_err();
+
+    assert!(err.contains("does not match operand type"), "{}", err);
+    assert!(err.contains("ret i32"), "{}", err);
+}
+
 #[test]
 fn test_metadata() {
     let context = Context::create();
@@ -553,9 +606,9 @@ fn test_metadata() {
 
     let entry_block = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry_block);
+    builder.position_at_end(entry_block).unwrap();
 
-    let ret_instr = builder.build_return(None);
+    let ret_instr = builder.build_return(None).unwrap();
 
     ret_instr.set_metadata(md_string, 2);
 
@@ -578,6 +631,56 @@ fn test_metadata() {
     assert!(context_metadata_string.is_string());
 }
 
+#[test]
+fn test_metadata_node_printing() {
+    let context = Context::create();
+    let i32_seven = context.i32_type().const_int(7, false);
+    let md_string = context.metadata_string("my_string");
+    let md_node = context.metadata_node(&[md_string.into(), i32_seven.into()]);
+
+    assert!(md_string.is_string());
+    assert!(md_node.is_node());
+    assert_eq!(md_node.get_node_size(), 2);
+    assert!(md_string
+        .print_to_string()
+        .to_string()
+        .ends_with("!\"my_string\""));
+    assert!(md_node
+        .print_to_string()
+        .to_string()
+        .ends_with("!{!\"my_string\", i32 7}"));
+}
+
+#[llvm_versions(8.0..=latest)]
+#[test]
+fn test_global_metadata() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "my_global");
+    let kind_id = context.get_kind_id("my_annotation");
+    let md_string = context.metadata_string("annotated");
+    let md_node = context.metadata_node(&[md_string.into()]);
+
+    assert!(global.get_all_metadata().is_empty());
+
+    global.set_metadata(md_node, kind_id);
+
+    let metadata = global.get_all_metadata();
+
+    assert_eq!(metadata.len(), 1);
+    assert_eq!(metadata[0].0, kind_id);
+    assert!(metadata[0].1.is_node());
+
+    let md_node_values = metadata[0].1.get_node_values();
+
+    assert_eq!(md_node_values.len(), 1);
+    assert_eq!(
+        md_node_values[0].into_metadata_value().get_string_value(),
+        md_string.get_string_value()
+    );
+}
+
 #[test]
 fn test_floats() {
     let context = Context::create();
@@ -811,7 +914,7 @@ fn test_global_byte_array() {
 
     let const_str_array = i8_type.const_array(chars.as_ref());
 
-    global_string.set_initializer(&const_str_array);
+    global_string.set_initializer(&const_str_array).unwrap();
 
     assert!(module.verify().is_ok());
 }
@@ -888,7 +991,7 @@ fn test_globals() {
     )))]
     global.set_unnamed_address(UnnamedAddress::Local);
     global.set_dll_storage_class(DLLStorageClass::Import);
-    global.set_initializer(&i8_zero);
+    global.set_initializer(&i8_zero).unwrap();
     global.set_thread_local_mode(Some(ThreadLocalMode::InitialExecTLSModel));
     global.set_unnamed_addr(true);
     global.set_constant(true);
@@ -1027,8 +1130,189 @@ fn test_globals() {
     }
 
     unsafe {
-        global.delete();
+        global.delete().unwrap();
+    }
+}
+
+#[llvm_versions(7.0..=latest)]
+#[test]
+fn test_global_string_unnamed_address() {
+    use inkwell::values::UnnamedAddress;
+
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+
+    builder.position_at_end(entry).unwrap();
+
+    let global_string = builder.build_global_string_ptr("my_string", "my_global_string");
+
+    global_string.set_unnamed_address(UnnamedAddress::None);
+
+    assert_eq!(global_string.get_unnamed_address(), UnnamedAddress::None);
+    assert!(!global_string
+        .print_to_string()
+        .to_string()
+        .contains("unnamed_addr"));
+
+    global_string.set_unnamed_address(UnnamedAddress::Local);
+
+    assert_eq!(global_string.get_unnamed_address(), UnnamedAddress::Local);
+    assert!(global_string
+        .print_to_string()
+        .to_string()
+        .contains("local_unnamed_addr"));
+
+    global_string.set_unnamed_address(UnnamedAddress::Global);
+
+    let global_string_ir = global_string.print_to_string().to_string();
+
+    assert_eq!(global_string.get_unnamed_address(), UnnamedAddress::Global);
+    assert!(
+        global_string_ir.contains(" unnamed_addr"),
+        "{}",
+        global_string_ir
+    );
+    assert!(
+        !global_string_ir.contains("local_unnamed_addr"),
+        "{}",
+        global_string_ir
+    );
+}
+
+#[llvm_versions(3.9..=latest)]
+#[test]
+fn test_personality_function() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let void_type = context.void_type();
+    let personality_type = i32_type.fn_type(&[], true);
+    let personality = module.add_function("__gxx_personality_v0", personality_type, None);
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+
+    assert!(!fn_value.has_personality_function());
+    assert!(fn_value.get_personality_function().is_none());
+
+    fn_value.set_personality_function(personality);
+
+    assert!(fn_value.has_personality_function());
+    assert_eq!(fn_value.get_personality_function(), Some(personality));
+    assert_eq!(
+        fn_value
+            .get_personality_function()
+            .unwrap()
+            .get_name()
+            .to_str(),
+        Ok("__gxx_personality_v0")
+    );
+}
+
+#[test]
+fn test_const_gep() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let array_type = i32_type.array_type(4);
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Zero);
+    let array = module.add_global(array_type, Some(AddressSpace::Zero), "my_array");
+    let array_ptr = array.as_pointer_value();
+    let indexes = [i32_type.const_zero(), i32_type.const_int(2, false)];
+
+    array.set_initializer(&array_type.const_zero()).unwrap();
+
+    let third_element_ptr = unsafe { array_ptr.const_gep(array_type, &indexes).unwrap() };
+    let in_bounds_third_element_ptr =
+        unsafe { array_ptr.const_in_bounds_gep(array_type, &indexes).unwrap() };
+
+    assert!(third_element_ptr.is_const());
+    assert!(third_element_ptr.as_instruction().is_none());
+    assert_eq!(third_element_ptr.get_type(), i32_ptr_type);
+    assert_eq!(
+        in_bounds_third_element_ptr
+            .print_to_string()
+            .to_string()
+            .trim(),
+        "i32* getelementptr inbounds ([4 x i32], [4 x i32]* @my_array, i32 0, i32 2)"
+    );
+
+    let element_ptr_global = module.add_global(i32_ptr_type, None, "third_element_ptr");
+
+    element_ptr_global
+        .set_initializer(&in_bounds_third_element_ptr)
+        .unwrap();
+
+    unsafe {
+        assert!(array_ptr.const_gep(i64_type, &indexes).is_err());
+    }
+
+    let fn_type = context.void_type().fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let entry = context.append_basic_block(fn_value, "entry");
+    let param = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(entry).unwrap();
+
+    let alloca = builder.build_alloca(array_type, "alloca").unwrap();
+
+    unsafe {
+        assert!(array_ptr
+            .const_gep(array_type, &[i32_type.const_zero(), param])
+            .is_err());
+        assert!(alloca.const_gep(array_type, &indexes).is_err());
+    }
+
+    builder.build_return(None).unwrap();
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_global_initializer_type_check() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i8_type = context.i8_type();
+    let i16_type = context.i16_type();
+    let global = module.add_global(i8_type, None, "my_global");
+
+    assert!(global.set_initializer(&i16_type.const_zero()).is_err());
+    assert!(global.get_initializer().is_none());
+    assert!(global.set_initializer(&i8_type.const_int(1, false)).is_ok());
+    assert_eq!(
+        global.get_initializer().unwrap().into_int_value(),
+        i8_type.const_int(1, false)
+    );
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_global_delete() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i8_type = context.i8_type();
+    let unused = module.add_global(i8_type, None, "unused");
+    let used = module.add_global(i8_type, None, "used");
+    let user = module.add_global(i8_type.ptr_type(AddressSpace::Zero), None, "user");
+
+    user.set_initializer(&used.as_pointer_value()).unwrap();
+
+    unsafe {
+        assert!(unused.delete().is_ok());
+        assert!(used.delete().is_err());
     }
+
+    assert!(module.get_global("unused").is_none());
+    assert_eq!(module.get_first_global(), Some(used));
+    assert_eq!(used.get_next_global(), Some(user));
+    assert!(user.get_next_global().is_none());
+    assert!(module.verify().is_ok());
 }
 
 #[test]
@@ -1049,11 +1333,11 @@ fn test_phi_values() {
 
     assert!(!fn_value.as_global_value().is_declaration());
 
-    builder.position_at_end(entry_block);
+    builder.position_at_end(entry_block).unwrap();
 
     let false_val = bool_type.const_int(0, false);
     let true_val = bool_type.const_int(1, false);
-    let phi = builder.build_phi(bool_type, "if");
+    let phi = builder.build_phi(bool_type, "if").unwrap();
 
     assert!(!phi.is_null());
     assert!(!phi.is_undef());
@@ -1093,7 +1377,7 @@ fn test_allocations() {
     let fn_value = module.add_function("my_func", fn_type, None);
     let entry_block = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(entry_block);
+    builder.position_at_end(entry_block).unwrap();
 
     // REVIEW: Alloca (and possibly malloc) seem to be prone to segfaulting
     // when called with a builder that isn't positioned. I wonder if other
@@ -1102,11 +1386,13 @@ fn test_allocations() {
     // methods on positioned variant if so. But leave positioning methods
     // on both?
 
-    let stack_ptr = builder.build_alloca(i32_type, "stack_ptr");
+    let stack_ptr = builder.build_alloca(i32_type, "stack_ptr").unwrap();
 
     assert_eq!(stack_ptr.get_type().print_to_string().to_str(), Ok("i32*"));
 
-    let stack_array = builder.build_array_alloca(i32_type, i32_three, "stack_array");
+    let stack_array = builder
+        .build_array_alloca(i32_type, i32_three, "stack_array")
+        .unwrap();
 
     assert_eq!(
         stack_array.get_type().print_to_string().to_str(),
@@ -1297,7 +1583,7 @@ fn test_consts() {
     let function = module.add_function("fn", fn_type, None);
     let basic_block = context.append_basic_block(function, "entry");
 
-    builder.position_at_end(basic_block);
+    builder.position_at_end(basic_block).unwrap();
 
     let i32_param = function.get_first_param().unwrap().into_int_value();
     let f32_param = function.get_nth_param(1).unwrap().into_float_value();
@@ -1321,8 +1607,8 @@ fn test_function_value_to_global_to_pointer() {
 
     let bb = context.append_basic_block(fn_value, "entry");
 
-    builder.position_at_end(bb);
-    builder.build_return(None);
+    builder.position_at_end(bb).unwrap();
+    builder.build_return(None).unwrap();
 
     assert!(!fn_global_value.is_declaration());
     assert_eq!(
@@ -1348,7 +1634,6 @@ fn test_function_value_to_global_to_pointer() {
 }
 
 #[test]
-#[should_panic]
 fn test_non_fn_ptr_called() {
     let context = Context::create();
     let builder = context.create_builder();
@@ -1360,11 +1645,13 @@ fn test_non_fn_ptr_called() {
     let bb = context.append_basic_block(fn_value, "entry");
     let i8_ptr_param = fn_value.get_first_param().unwrap().into_pointer_value();
 
-    builder.position_at_end(bb);
-    builder.build_call(i8_ptr_param, &[], "call");
-    builder.build_return(None);
+    builder.position_at_end(bb).unwrap();
 
-    assert!(module.verify().is_ok());
+    assert!(builder.build_call(i8_ptr_param, &[], "call").is_err());
+    assert!(builder
+        .build_invoke(i8_ptr_param, &[], bb, bb, "call")
+        .is_err());
+    assert!(bb.get_first_instruction().is_none());
 }
 
 #[test]
@@ -1381,12 +1668,67 @@ fn test_vectors() {
     let bb = context.append_basic_block(fn_value, "entry");
     let vector_param = fn_value.get_first_param().unwrap().into_vector_value();
 
-    builder.position_at_end(bb);
-    builder.build_insert_element(vector_param, i32_seven, i32_zero, "insert");
+    builder.position_at_end(bb).unwrap();
+    builder
+        .build_insert_element(vector_param, i32_seven, i32_zero, "insert")
+        .unwrap();
 
-    let extracted = builder.build_extract_element(vector_param, i32_zero, "extract");
+    let extracted = builder
+        .build_extract_element(vector_param, i32_zero, "extract")
+        .unwrap();
 
-    builder.build_return(Some(&extracted));
+    builder.build_return(Some(&extracted)).unwrap();
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_vector_element_bounds() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i32_two = i32_type.const_int(2, false);
+    let i32_four = i32_type.const_int(4, false);
+    let i32_seven = i32_type.const_int(7, false);
+    let vector = VectorType::const_vector(&[
+        i32_type.const_int(10, false),
+        i32_type.const_int(11, false),
+        i32_type.const_int(12, false),
+        i32_type.const_int(13, false),
+    ]);
+
+    let lane = vector.const_extract_element(i32_two).into_int_value();
+
+    assert_eq!(lane.get_zero_extended_constant(), Some(12));
+
+    let inserted = vector
+        .const_insert_element(i32_two, i32_seven)
+        .into_vector_value();
+    let lane = inserted.const_extract_element(i32_two).into_int_value();
+
+    assert_eq!(lane.get_zero_extended_constant(), Some(7));
+
+    let vec_type = i32_type.vec_type(4);
+    let fn_type = i32_type.fn_type(&[vec_type.into()], false);
+    let fn_value = module.add_function("my_func", fn_type, None);
+    let bb = context.append_basic_block(fn_value, "entry");
+    let vector_param = fn_value.get_first_param().unwrap().into_vector_value();
+
+    builder.position_at_end(bb).unwrap();
+
+    assert!(builder
+        .build_extract_element(vector_param, i32_four, "extract")
+        .is_err());
+    assert!(builder
+        .build_insert_element(vector_param, i32_seven, i32_four, "insert")
+        .is_err());
+
+    let extracted = builder
+        .build_extract_element(vector_param, i32_two, "extract")
+        .unwrap();
+
+    builder.build_return(Some(&extracted)).unwrap();
 
     assert!(module.verify().is_ok());
 }
@@ -1407,9 +1749,13 @@ fn test_aggregate_returns() {
     let ptr_param1 = fn_value.get_first_param().unwrap().into_pointer_value();
     let ptr_param2 = fn_value.get_nth_param(1).unwrap().into_pointer_value();
 
-    builder.position_at_end(bb);
-    builder.build_ptr_diff(ptr_param1, ptr_param2, "diff");
-    builder.build_aggregate_return(&[i32_three.into(), i32_seven.into()]);
+    builder.position_at_end(bb).unwrap();
+    builder
+        .build_ptr_diff(ptr_param1, ptr_param2, "diff")
+        .unwrap();
+    builder
+        .build_aggregate_return(&[i32_three.into(), i32_seven.into()])
+        .unwrap();
 
     assert!(module.verify().is_ok());
 }
@@ -1425,9 +1771,236 @@ fn test_constant_expression() {
     let fn_type = void_type.fn_type(&[], false);
 
     let function = module.add_function("", fn_type, None);
-    let expr =
-        builder.build_ptr_to_int(function.as_global_value().as_pointer_value(), i32_type, "");
+    let expr = builder
+        .build_ptr_to_int(function.as_global_value().as_pointer_value(), i32_type, "")
+        .unwrap();
 
     assert!(expr.is_const());
     assert!(!expr.is_constant_int());
 }
+
+#[llvm_versions(7.0..=latest)]
+#[test]
+fn test_global_alias() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let global = module.add_global(i32_type, None, "my_global");
+    let other_global = module.add_global(i32_type, None, "my_other_global");
+
+    global
+        .set_initializer(&i32_type.const_int(1, false))
+        .unwrap();
+    other_global
+        .set_initializer(&i32_type.const_int(2, false))
+        .unwrap();
+
+    assert!(module
+        .add_alias(i64_type, AddressSpace::Zero, global, "bad_alias")
+        .is_err());
+    assert!(module
+        .add_alias(i32_type, AddressSpace::One, global, "bad_alias")
+        .is_err());
+    assert!(global.get_aliasee().is_none());
+    assert!(global.set_aliasee(other_global).is_err());
+
+    let alias = module
+        .add_alias(i32_type, AddressSpace::Zero, global, "my_alias")
+        .unwrap();
+    let alias_of_alias = module
+        .add_alias(i32_type, AddressSpace::Zero, alias, "my_alias_of_alias")
+        .unwrap();
+
+    assert_eq!(alias.get_aliasee(), Some(global.as_pointer_value()));
+    assert_eq!(alias_of_alias.get_aliasee(), Some(alias.as_pointer_value()));
+    assert_eq!(module.get_global("my_global"), Some(global));
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(
+        ir.contains("@my_alias = alias i32, i32* @my_global"),
+        "{}",
+        ir
+    );
+    assert!(
+        ir.contains("@my_alias_of_alias = alias i32, i32* @my_alias"),
+        "{}",
+        ir
+    );
+
+    assert!(alias
+        .set_aliasee(i64_type.const_zero().as_basic_value_enum())
+        .is_err());
+    assert!(alias.set_aliasee(other_global).is_ok());
+    assert_eq!(alias.get_aliasee(), Some(other_global.as_pointer_value()));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_const_array_checked() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let array_type = i32_type.array_type(3);
+    let one = i32_type.const_int(1, false);
+    let two = i32_type.const_int(2, false);
+
+    let array = array_type
+        .const_array_checked(&[one.into(), two.into(), one.into()])
+        .unwrap();
+
+    assert!(array.is_const());
+    assert_eq!(array.get_type(), array_type);
+    assert_eq!(
+        array.print_to_string().to_string(),
+        "[3 x i32] [i32 1, i32 2, i32 1]"
+    );
+
+    assert!(array_type
+        .const_array_checked(&[one.into(), two.into()])
+        .is_err());
+    assert!(array_type
+        .const_array_checked(&[one.into(), two.into(), i64_type.const_int(1, false).into()])
+        .is_err());
+
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+    let param = fn_value.get_first_param().unwrap();
+
+    assert!(array_type
+        .const_array_checked(&[one.into(), two.into(), param])
+        .is_err());
+
+    let struct_val = context.const_struct(&[one.into(), i64_type.const_zero().into()], false);
+
+    assert!(struct_val.is_const());
+    assert_eq!(
+        struct_val.get_type().get_field_types(),
+        &[i32_type.into(), i64_type.into()]
+    );
+}
+
+#[test]
+fn test_fn_call_convention() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", fn_type, None);
+
+    assert_eq!(fn_value.get_call_convention(), Some(CallConv::C));
+
+    fn_value.set_call_convention(CallConv::Fast);
+
+    assert_eq!(fn_value.get_call_convention(), Some(CallConv::Fast));
+    assert_eq!(fn_value.get_call_conventions(), 8);
+    assert!(fn_value
+        .print_to_string()
+        .to_string()
+        .contains("declare fastcc void @my_fn()"));
+
+    fn_value.set_call_conventions(CallConv::X86VectorCall as u32);
+
+    assert_eq!(
+        fn_value.get_call_convention(),
+        Some(CallConv::X86VectorCall)
+    );
+
+    // Target specific conventions without a variant are only reachable through their ids
+    fn_value.set_call_conventions(1024);
+
+    assert_eq!(fn_value.get_call_convention(), None);
+    assert_eq!(fn_value.get_call_conventions(), 1024);
+    assert_eq!(CallConv::try_from(9), Ok(CallConv::Cold));
+}
+
+#[test]
+fn test_externally_initialized_round_trip() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "host_data");
+
+    global.set_initializer(&i32_type.const_zero()).unwrap();
+    global.set_externally_initialized(true);
+
+    assert!(global.is_externally_initialized());
+    assert!(global
+        .print_to_string()
+        .to_string()
+        .contains("externally_initialized"));
+
+    let buffer = module.write_bitcode_to_memory();
+    let module2 = Module::parse_bitcode_from_buffer(&buffer, &context).unwrap();
+    let global2 = module2.get_global("host_data").unwrap();
+
+    assert!(global2.is_externally_initialized());
+
+    global2.set_externally_initialized(false);
+
+    assert!(!global2.is_externally_initialized());
+    assert!(!global2
+        .print_to_string()
+        .to_string()
+        .contains("externally_initialized"));
+}
+
+#[test]
+fn test_constant_extraction() {
+    let context = Context::create();
+    let f64_type = context.f64_type();
+    let f32_type = context.f32_type();
+    let i128_type = context.i128_type();
+    let i96_type = context.custom_width_int_type(96);
+    let i256_type = context.custom_width_int_type(256);
+
+    assert_eq!(
+        f64_type.const_float(3.14).get_constant(),
+        Some((3.14, false))
+    );
+
+    let (f32_pi, _) = f32_type.const_float(3.14).get_constant().unwrap();
+
+    assert_eq!(f32_pi, 3.14f32 as f64);
+
+    let large = 0x1234_5678_9abc_def0_0fed_cba9_8765_4321u128;
+    let i128_val = i128_type.const_int_arbitrary_precision(&[large as u64, (large >> 64) as u64]);
+
+    assert_eq!(i128_val.get_zero_extended_constant(), None);
+    assert_eq!(i128_val.get_zero_extended_constant_u128(), Some(large));
+    assert_eq!(
+        i128_val.get_sign_extended_constant_i128(),
+        Some(large as i128)
+    );
+
+    let i128_min = i128_type.const_int_arbitrary_precision(&[0, 1 << 63]);
+
+    assert_eq!(i128_min.get_zero_extended_constant_u128(), Some(1 << 127));
+    assert_eq!(
+        i128_min.get_sign_extended_constant_i128(),
+        Some(i128::min_value())
+    );
+
+    let i96_all_ones = i96_type.const_all_ones();
+
+    assert_eq!(
+        i96_all_ones.get_zero_extended_constant_u128(),
+        Some((1 << 96) - 1)
+    );
+    assert_eq!(i96_all_ones.get_sign_extended_constant_i128(), Some(-1));
+
+    let i8_val = context.i8_type().const_all_ones();
+
+    assert_eq!(i8_val.get_zero_extended_constant_u128(), Some(255));
+    assert_eq!(i8_val.get_sign_extended_constant_i128(), Some(-1));
+
+    assert_eq!(
+        i256_type.const_all_ones().get_zero_extended_constant_u128(),
+        None
+    );
+    assert_eq!(
+        i256_type.const_all_ones().get_sign_extended_constant_i128(),
+        None
+    );
+}